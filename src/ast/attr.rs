@@ -39,7 +39,7 @@ pub enum AttributeValue {
         eq: Token![=],
         #[sourcery(spaces = 0)]
         t4: Trivia,
-        expr: Expr,
+        expr: Box<Expr>,
     },
     List(#[sourcery(spaces = 0)] Trivia, #[sourcery(spaces = "ignore")] Delimited<TokenStream>),
 }
@@ -50,15 +50,14 @@ use crate::passes::style::spaces::*;
 impl Respace for List<Attribute> {
     fn respace(&mut self, v: &mut Spaces) {
         let (mut attrs, mut last) = self.take().into_parts();
+        if let Some((_, t)) = attrs.last_mut() {
+            t.extend(last.take());
+        }
         for (a, t) in &mut attrs {
             a.respace(v);
             s1(t);
         }
-        if attrs.is_empty() {
-            s0(&mut last);
-        } else {
-            s1(&mut last);
-        }
+        s0(&mut last);
 
         *self = List::from_parts(attrs, last);
     }
@@ -8,9 +8,9 @@ pub enum ExprKind {
     TryBlock(TryBlock),
     Unsafe(UnsafeBlock),
     Const(ConstBlock),
-    If(IfExpr),
-    While(While),
-    For(For),
+    If(Box<IfExpr>),
+    While(Box<While>),
+    For(Box<For>),
     Loop(Loop),
     Match(Match),
     Break(Break),
@@ -19,33 +19,75 @@ pub enum ExprKind {
     Yield(Yield),
     Become(Become),
     Let(ExprLet),
-    QPath(QPath),
-    Struct(ExprStruct),
+    QPath(Box<QPath>),
+    Struct(Box<ExprStruct>),
     Tuple(Parens<CommaSepExprs>),
     Paren(Parens<ExprParen>),
     Array(Brackets<CommaSepExprs>),
     Repeat(Brackets<ExprRepeat>),
-    Macro(MacroCall),
-    Closure(Closure),
+    Macro(Box<MacroCall>),
+    Closure(Box<Closure>),
     Range(ExprRange),
     Call(ExprCall),
+    Binary(ExprBinary),
+    Assign(ExprAssign),
+    AssignOp(ExprAssignOp),
+    Unary(ExprUnary),
+    Ref(ExprRef),
+    Field(ExprField),
+    MethodCall(Box<ExprMethodCall>),
+    Index(ExprIndex),
+    Try(ExprTry),
+    Await(ExprAwait),
+    Use(ExprUse),
+    PostfixTry(ExprPostfixTry),
+    PostfixMatch(ExprPostfixMatch),
+    Cast(ExprCast),
+    Yeet(Yeet),
+    Builtin(Builtin),
 }
 
-#[derive(Debug, Print, Walk, Respace)]
+#[derive(Debug, Print, Walk)]
+pub enum CoroutineKind {
+    Async(Token![async]),
+    Gen(Token![gen]),
+    AsyncGen(Token![async], TriviaN, Token![gen]),
+}
+
+impl Respace for CoroutineKind {
+    fn respace(&mut self, _: &mut Spaces) {
+        if let CoroutineKind::AsyncGen(_, t, _) = self {
+            s1(t);
+        }
+    }
+}
+
+// async move {}, gen {} or async gen {}
+#[derive(Debug, Print, Walk)]
 pub struct AsyncBlock {
-    pub token: Token![async],
-    #[sourcery(spaces = 1)]
+    pub kind: CoroutineKind,
     pub t1: Trivia,
-    // TODO `move`
+    pub move_: Option<(Token![move], Trivia)>,
     pub block: Block,
 }
 
+impl Respace for AsyncBlock {
+    fn respace(&mut self, v: &mut Spaces) {
+        let AsyncBlock { kind, t1, move_, block } = self;
+        kind.respace(v);
+        s1(t1);
+        if let Some((_, t)) = move_ {
+            s1(t);
+        }
+        block.respace(v);
+    }
+}
+
 #[derive(Debug, Print, Walk, Respace)]
 pub struct TryBlock {
     pub token: Token![try],
     #[sourcery(spaces = 1)]
     pub t1: Trivia,
-    // TODO `move`
     pub block: Block,
 }
 
@@ -144,6 +186,36 @@ pub struct Arm {
     pub comma: Option<(Trivia, Token![,])>,
 }
 
+impl Respace for Arm {
+    fn respace(&mut self, v: &mut Spaces) {
+        let Arm { attrs, pat, guard, t1, arrow: _, t2, body, comma } = self;
+        attrs.respace(v);
+        pat.respace(v);
+        if let Some((t1, _, t2, cond)) = guard {
+            s1(t1);
+            s1(t2);
+            cond.respace(v);
+        }
+        s1(t1);
+        s1(t2);
+        body.respace(v);
+        if let Some((t, _)) = comma {
+            s0(t);
+        }
+    }
+}
+
+// the trivia between arms holds the line breaks, so only the arms themselves are respaced
+impl Respace for List<Arm> {
+    fn respace(&mut self, v: &mut Spaces) {
+        let (mut arms, last) = self.take().into_parts();
+        for (arm, _) in &mut arms {
+            arm.respace(v);
+        }
+        *self = List::from_parts(arms, last);
+    }
+}
+
 #[derive(Debug, Print, Walk)]
 pub struct Match {
     pub token: Token![match],
@@ -154,8 +226,12 @@ pub struct Match {
 }
 
 impl Respace for Match {
-    fn respace(&mut self, _: &mut Spaces) {
-        todo!()
+    fn respace(&mut self, v: &mut Spaces) {
+        let Match { token: _, t1, expr, t2, arms } = self;
+        s1(t1);
+        expr.respace(v);
+        s1(t2);
+        arms.0.1.respace(v);
     }
 }
 
@@ -184,6 +260,43 @@ pub struct Yield {
     pub expr: Option<L<Box<Expr>>>,
 }
 
+// do yeet expr
+#[derive(Debug, Print, Walk)]
+pub struct Yeet {
+    pub do_: Token![do],
+    pub t1: TriviaN,
+    pub yeet: Token![yeet],
+    pub expr: Option<L<Box<Expr>>>,
+}
+
+impl Respace for Yeet {
+    fn respace(&mut self, v: &mut Spaces) {
+        s1(&mut self.t1);
+        self.expr.respace(v);
+    }
+}
+
+// builtin # offset_of(Ty, field)
+#[derive(Debug, Print, Walk)]
+pub struct Builtin {
+    pub kw: Token![builtin],
+    pub t1: Trivia,
+    pub pound: Token![#],
+    pub t2: Trivia,
+    pub name: Ident,
+    pub t3: Trivia,
+    pub args: Parens<TokenStream>,
+}
+
+impl Respace for Builtin {
+    fn respace(&mut self, _: &mut Spaces) {
+        let Builtin { kw: _, t1, pound: _, t2, name: _, t3, args: _ } = self;
+        s1(t1);
+        s1(t2);
+        s0(t3);
+    }
+}
+
 #[derive(Debug, Print, Walk, Respace)]
 pub struct Become {
     pub token: Token![become],
@@ -235,8 +348,9 @@ impl Respace for ExprStruct {
     }
 }
 
-#[derive(Debug, Print, Walk)]
+#[derive(Debug, Print, Walk, Respace)]
 pub struct CommaSepExprs {
+    #[sourcery(spaces = 0)]
     pub t1: Trivia,
     pub contents: SeparatedList<Expr, Token![,]>,
 }
@@ -259,18 +373,6 @@ pub struct ExprRepeat {
     pub t4: Trivia,
 }
 
-impl Respace for Parens<CommaSepExprs> {
-    fn respace(&mut self, _: &mut Spaces) {
-        todo!()
-    }
-}
- 
-impl Respace for Brackets<CommaSepExprs> {
-    fn respace(&mut self, _: &mut Spaces) {
-        todo!()
-    }
-}
-
 impl Respace for Parens<ExprParen> {
     fn respace(&mut self, _: &mut Spaces) {
         todo!()
@@ -291,8 +393,48 @@ pub struct ClosureArg {
     pub comma: Option<(Trivia, Token![,])>,
 }
 
+impl Respace for ClosureArg {
+    fn respace(&mut self, v: &mut Spaces) {
+        let ClosureArg { attrs, pat, ty, comma } = self;
+        attrs.respace(v);
+        pat.respace(v);
+        if let Some((t1, _, t2, ty)) = ty {
+            s0(t1);
+            s1(t2);
+            ty.respace(v);
+        }
+        if let Some((t, _)) = comma {
+            s0(t);
+        }
+    }
+}
+
+impl Respace for List<ClosureArg> {
+    fn respace(&mut self, v: &mut Spaces) {
+        let (mut args, mut last) = self.take().into_parts();
+        if let Some((_, t)) = args.last_mut() {
+            t.extend(last.take());
+        }
+        for (arg, t) in &mut args {
+            arg.respace(v);
+            s1(t);
+        }
+        // no space before the closing `|`
+        if let Some((_, t)) = args.last_mut() {
+            s0(t);
+        }
+        s0(&mut last);
+        *self = List::from_parts(args, last);
+    }
+}
+
+// for<'a> static async move |args| -> Ty { body }
 #[derive(Debug, Print, Walk)]
 pub struct Closure {
+    pub binder: Option<(BoundLifetimes, Trivia)>,
+    pub static_: Option<(Token![static], Trivia)>,
+    pub async_: Option<(Token![async], Trivia)>,
+    pub move_: Option<(Token![move], Trivia)>,
     pub bar1: Token![|],
     pub t1: Trivia,
     pub args: List<ClosureArg>,
@@ -303,15 +445,34 @@ pub struct Closure {
 }
 
 impl Respace for Closure {
-    fn respace(&mut self, _: &mut Spaces) {
-        todo!()
+    fn respace(&mut self, v: &mut Spaces) {
+        let Closure { binder, static_, async_, move_, bar1: _, t1, args, bar2: _, ret, t2, body } = self;
+        binder.respace(v);
+        if let Some((_, t)) = static_ {
+            s1(t);
+        }
+        if let Some((_, t)) = async_ {
+            s1(t);
+        }
+        if let Some((_, t)) = move_ {
+            s1(t);
+        }
+        s0(t1);
+        args.respace(v);
+        if let Some((t, FnRet { arrow: _, t2_5, ty })) = ret {
+            s1(t);
+            s1(t2_5);
+            ty.respace(v);
+        }
+        s1(t2);
+        body.respace(v);
     }
 }
 
 #[derive(Debug, Print, Walk)]
 pub enum RangeLimits {
-    HalfOpen(Token![..=]),
-    Closed(Token![..]),
+    HalfOpen(Token![..]),
+    Closed(Token![..=]),
 }
 
 #[derive(Debug, Print, Walk)]
@@ -322,19 +483,290 @@ pub struct ExprRange {
 }
 
 impl Respace for ExprRange {
-    fn respace(&mut self, _: &mut Spaces) {
-        todo!()
+    fn respace(&mut self, v: &mut Spaces) {
+        let ExprRange { start, limits: _, end } = self;
+        if let Some((start, t)) = start {
+            start.respace(v);
+            s0(t);
+        }
+        if let Some(L(t, end)) = end {
+            s0(t);
+            end.respace(v);
+        }
+    }
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum BinOp {
+    Add(Token![+]),
+    Sub(Token![-]),
+    Mul(Token![*]),
+    Div(Token![/]),
+    Rem(Token![%]),
+    And(Token![&&]),
+    Or(Token![||]),
+    BitXor(Token![^]),
+    BitAnd(Token![&]),
+    BitOr(Token![|]),
+    Shl(Token![<<]),
+    Shr(Token![>>]),
+    Eq(Token![==]),
+    Lt(Token![<]),
+    Le(Token![<=]),
+    Ne(Token![!=]),
+    Ge(Token![>=]),
+    Gt(Token![>]),
+}
+
+// a + b
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprBinary {
+    pub lhs: Box<Expr>,
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub op: BinOp,
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub rhs: Box<Expr>,
+}
+
+// a = b
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprAssign {
+    pub lhs: Box<Expr>,
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub eq: Token![=],
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub rhs: Box<Expr>,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum AssignOp {
+    AddAssign(Token![+=]),
+    SubAssign(Token![-=]),
+    MulAssign(Token![*=]),
+    DivAssign(Token![/=]),
+    RemAssign(Token![%=]),
+    BitXorAssign(Token![^=]),
+    BitAndAssign(Token![&=]),
+    BitOrAssign(Token![|=]),
+    ShlAssign(Token![<<=]),
+    ShrAssign(Token![>>=]),
+}
+
+// a += b
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprAssignOp {
+    pub lhs: Box<Expr>,
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub op: AssignOp,
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub rhs: Box<Expr>,
+}
+
+// a as T
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprCast {
+    pub expr: Box<Expr>,
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub as_: Token![as],
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub ty: Box<Ty>,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum UnOp {
+    Deref(Token![*]),
+    Not(Token![!]),
+    Neg(Token![-]),
+}
+
+// !a
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprUnary {
+    pub op: UnOp,
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub expr: Box<Expr>,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum BorrowKind {
+    // &a
+    Ref,
+    // &mut a
+    Mut(#[sourcery(spaces = 0)] Trivia, Token![mut]),
+    // &raw const a
+    RawConst(
+        #[sourcery(spaces = 0)] Trivia,
+        Token![raw],
+        #[sourcery(spaces = 1)] Trivia,
+        Token![const],
+    ),
+    // &raw mut a
+    RawMut(
+        #[sourcery(spaces = 0)] Trivia,
+        Token![raw],
+        #[sourcery(spaces = 1)] Trivia,
+        Token![mut],
+    ),
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct ExprRef {
+    pub and: Token![&],
+    pub kind: BorrowKind,
+    pub t1: Trivia,
+    pub expr: Box<Expr>,
+}
+
+impl Respace for ExprRef {
+    fn respace(&mut self, v: &mut Spaces) {
+        let ExprRef { and: _, kind, t1, expr } = self;
+        kind.respace(v);
+        if let BorrowKind::Ref = kind {
+            s0(t1);
+        } else {
+            s1(t1);
+        }
+        expr.respace(v);
     }
 }
 
 #[derive(Debug, Print, Walk, Respace)]
 pub struct ExprCall {
     pub callee: Box<ExprKind>,
-    #[sourcery(spaces = 1)]
+    #[sourcery(spaces = 0)]
     pub t0: Trivia,
     pub args: Parens<CommaSepExprs>,
 }
 
+#[derive(Debug, Print, Walk, Respace)]
+pub enum Member {
+    Named(Ident),
+    // a tuple index, `a.0`
+    Unnamed(Literal),
+}
+
+// a.b
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprField {
+    pub expr: Box<ExprKind>,
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub dot: Token![.],
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+    pub member: Member,
+}
+
+// a.b::<T>(c)
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprMethodCall {
+    pub receiver: Box<ExprKind>,
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub dot: Token![.],
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+    pub method: PathSegment,
+    #[sourcery(spaces = 0)]
+    pub t3: Trivia,
+    pub args: Parens<CommaSepExprs>,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct IndexInner {
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+    pub index: Box<Expr>,
+    #[sourcery(spaces = 0)]
+    pub t3: Trivia,
+}
+
+// a[b]
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprIndex {
+    pub expr: Box<ExprKind>,
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub index: Brackets<IndexInner>,
+}
+
+// a?
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprTry {
+    pub expr: Box<ExprKind>,
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub question: Token![?],
+}
+
+// a.await
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprAwait {
+    pub expr: Box<ExprKind>,
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub dot: Token![.],
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+    pub await_: Token![await],
+}
+
+// expr.use
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprUse {
+    pub expr: Box<ExprKind>,
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub dot: Token![.],
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+    pub use_: Token![use],
+}
+
+// expr.try
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ExprPostfixTry {
+    pub expr: Box<ExprKind>,
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub dot: Token![.],
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+    pub try_: Token![try],
+}
+
+// expr.match { arms }
+#[derive(Debug, Print, Walk)]
+pub struct ExprPostfixMatch {
+    pub expr: Box<ExprKind>,
+    pub t1: Trivia,
+    pub dot: Token![.],
+    pub t2: Trivia,
+    pub match_: Token![match],
+    pub t3: Trivia,
+    pub arms: Braces<(Trivia, List<Arm>)>,
+}
+
+impl Respace for ExprPostfixMatch {
+    fn respace(&mut self, v: &mut Spaces) {
+        let ExprPostfixMatch { expr, t1, dot: _, t2, match_: _, t3, arms } = self;
+        expr.respace(v);
+        s0(t1);
+        s0(t2);
+        s1(t3);
+        arms.0.1.respace(v);
+    }
+}
+
 #[derive(Debug, Print, Walk, Respace)]
 pub struct Expr {
     pub attrs: List<Attribute>,
@@ -0,0 +1,223 @@
+use crate::prelude::*;
+
+// `<'a, T: Trait, const N: usize>`
+#[derive(Debug, Print, Walk, Respace)]
+pub struct Generics {
+    pub lt: Token![<],
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub params: SeparatedList<GenericParam, Token![,]>,
+    pub gt: Token![>],
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct GenericParam {
+    pub attrs: List<Attribute>,
+    pub kind: GenericParamKind,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum GenericParamKind {
+    Lifetime(LifetimeParam),
+    Type(Box<TypeParam>),
+    Const(Box<ConstParam>),
+}
+
+// 'a: 'b + 'c
+#[derive(Debug, Print, Walk, Respace)]
+pub struct LifetimeParam {
+    pub lifetime: Ident,
+    pub bounds: Option<LifetimeBounds>,
+}
+
+// T: Trait = Default
+#[derive(Debug, Print, Walk, Respace)]
+pub struct TypeParam {
+    pub name: Ident,
+    pub bounds: Option<TypeBounds>,
+    pub default: Option<TypeParamDefault>,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct TypeParamDefault {
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub eq: Token![=],
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub ty: Ty,
+}
+
+// const N: usize = 3
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ConstParam {
+    pub kw: Token![const],
+    #[sourcery(spaces = 1)]
+    pub t1: TriviaN,
+    pub name: Ident,
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+    pub colon: Token![:],
+    #[sourcery(spaces = 1)]
+    pub t3: Trivia,
+    pub ty: Ty,
+    pub default: Option<ConstParamDefault>,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ConstParamDefault {
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub eq: Token![=],
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub expr: Expr,
+}
+
+// : 'a + 'b
+#[derive(Debug, Print, Walk, Respace)]
+pub struct LifetimeBounds {
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub colon: Token![:],
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub bounds: SeparatedList<Ident, Token![+]>,
+}
+
+// : Trait + 'a
+#[derive(Debug, Print, Walk, Respace)]
+pub struct TypeBounds {
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub colon: Token![:],
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub bounds: SeparatedList<TypeParamBound, Token![+]>,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum TypeParamBound {
+    Lifetime(Ident),
+    Trait(TraitBound),
+    // (?Sized)
+    Paren(Parens<ParenBound>),
+    // use<'a, T>
+    Use(PreciseCapturing),
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ParenBound {
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub bound: TraitBound,
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum BoundConstness {
+    Const(Token![const]),
+    // ~const
+    Maybe(Token![~], #[sourcery(spaces = 0)] Trivia, Token![const]),
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum BoundPolarity {
+    Maybe(Token![?]),
+    Negative(Token![!]),
+}
+
+// for<'a> ~const async ?Trait
+#[derive(Debug, Print, Walk)]
+pub struct TraitBound {
+    pub bound_lifetimes: Option<(BoundLifetimes, Trivia)>,
+    pub constness: Option<(BoundConstness, Trivia)>,
+    pub async_: Option<(Token![async], Trivia)>,
+    pub polarity: Option<(BoundPolarity, Trivia)>,
+    pub path: Path,
+}
+
+impl Respace for TraitBound {
+    fn respace(&mut self, v: &mut Spaces) {
+        let TraitBound { bound_lifetimes, constness, async_, polarity, path } = self;
+        bound_lifetimes.respace(v);
+        if let Some((constness, t)) = constness {
+            constness.respace(v);
+            s1(t);
+        }
+        if let Some((_, t)) = async_ {
+            s1(t);
+        }
+        if let Some((_, t)) = polarity {
+            s0(t);
+        }
+        path.respace(v);
+    }
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum PreciseCapturingArg {
+    Lifetime(Ident),
+    Param(Ident),
+}
+
+// use<'a, T>
+#[derive(Debug, Print, Walk, Respace)]
+pub struct PreciseCapturing {
+    pub kw: Token![use],
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub lt: Token![<],
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+    pub args: SeparatedList<PreciseCapturingArg, Token![,]>,
+    pub gt: Token![>],
+}
+
+// for<'a>
+#[derive(Debug, Print, Walk, Respace)]
+pub struct BoundLifetimes {
+    pub for_: Token![for],
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub generics: Generics,
+}
+
+impl Respace for Option<(BoundLifetimes, Trivia)> {
+    fn respace(&mut self, v: &mut Spaces) {
+        if let Some((bound_lifetimes, t)) = self {
+            bound_lifetimes.respace(v);
+            s1(t);
+        }
+    }
+}
+
+// where T: Trait, 'a: 'b
+#[derive(Debug, Print, Walk, Respace)]
+pub struct WhereClause {
+    pub kw: Token![where],
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub predicates: SeparatedList<WherePredicate, Token![,]>,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum WherePredicate {
+    Lifetime(WhereLifetimePredicate),
+    Type(Box<WhereTypePredicate>),
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct WhereLifetimePredicate {
+    pub lifetime: Ident,
+    pub bounds: LifetimeBounds,
+}
+
+// for<'a> F: Fn(&'a u8)
+#[derive(Debug, Print, Walk, Respace)]
+pub struct WhereTypePredicate {
+    pub bound_lifetimes: Option<(BoundLifetimes, Trivia)>,
+    pub ty: Ty,
+    pub bounds: TypeBounds,
+}
@@ -2,9 +2,13 @@ use std::fmt::Debug;
 use sourcery_derive::{Respace, Walk};
 
 use crate::ast::tokens::Semi;
-use crate::ast::{Block, Expr, Parens, Pat, TriviaN, Ty};
+use crate::ast::{
+    Block, Expr, Generics, Macro2, MacroCall, MacroRules, Parens, Pat, Path, SeparatedList, TriviaN, Ty, TypeBounds,
+    TypeParamBound, WhereClause,
+};
+use crate::passes::style::spaces::{s0, s1, Respace, Spaces};
 use crate::Print;
-use super::{List, Attribute, Trivia, Ident, Visibility, Braces, Module, Token};
+use super::{List, Attribute, Trivia, Ident, Literal, Visibility, Braces, Module, Token};
 
 #[derive(Debug, Print, Walk)]
 pub enum ItemKind {
@@ -13,6 +17,19 @@ pub enum ItemKind {
     Mod(Mod),
     TyAlias(TyAlias),
     Fn(Fn),
+    Struct(Struct),
+    Union(Union),
+    Enum(Enum),
+    Trait(Trait),
+    TraitAlias(TraitAlias),
+    Impl(Impl),
+    Use(Use),
+    ExternCrate(ExternCrate),
+    ForeignMod(ForeignMod),
+    MacroRules(MacroRules),
+    Macro2(Macro2),
+    // `foo!(..);` or `foo! { .. }`, the semicolon is optional only for braces
+    MacCall(MacroCall, Option<(Trivia, Token![;])>),
 }
 
 #[derive(Debug, Print, Walk)]
@@ -67,22 +84,64 @@ pub struct TyAlias {
     pub kw: Token![type],
     pub t1: Trivia,
     pub name: Ident,
+    pub generics: Option<(Trivia, Generics)>,
+    // only meaningful for associated types
+    pub bounds: Option<TypeBounds>,
+    pub where_before: Option<(Trivia, WhereClause)>,
+    pub ty: Option<(Trivia, Token![=], Trivia, Ty)>,
+    pub where_after: Option<(Trivia, WhereClause)>,
     pub t2: Trivia,
-    pub eq: Token![=],
-    pub t3: Trivia,
-    pub ty: Ty,
-    pub t4: Trivia,
     pub semi: Token![;],
 }
 
+#[derive(Debug, Print, Walk)]
+pub enum SelfParam {
+    // self, mut self
+    Value {
+        mut_: Option<(Token![mut], Trivia)>,
+        self_: Token![self],
+    },
+    // &self, &'a mut self
+    Ref {
+        and: Token![&],
+        lifetime: Option<(Trivia, Ident)>,
+        mut_: Option<(Trivia, Token![mut])>,
+        t1: Trivia,
+        self_: Token![self],
+    },
+    // self: Ty, mut self: Ty
+    Typed {
+        mut_: Option<(Token![mut], Trivia)>,
+        self_: Token![self],
+        t1: Trivia,
+        colon: Token![:],
+        t2: Trivia,
+        ty: Ty,
+    },
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum FnParamKind {
+    // pat: Ty
+    Typed {
+        pat: Pat,
+        t1: Trivia,
+        colon: Token![:],
+        t2: Trivia,
+        ty: Ty,
+    },
+    SelfParam(SelfParam),
+    // `...` or `args: ...`
+    Variadic {
+        pat: Option<(Pat, Trivia, Token![:], Trivia)>,
+        dots: Token![...],
+    },
+}
+
 #[derive(Debug, Print, Walk)]
 pub struct FnParam {
     pub attrs: List<Attribute>,
-    pub pat: Pat,
-    pub t1: Trivia,
-    pub colon: Token![:],
-    pub t2: Trivia,
-    pub ty: Ty,
+    pub kind: FnParamKind,
     pub comma: Option<(Trivia, Token![,])>,
 }
 
@@ -93,17 +152,70 @@ pub struct FnRet {
     pub ty: Ty,
 }
 
+#[derive(Debug, Print, Walk)]
+pub enum Safety {
+    Unsafe(Token![unsafe]),
+    Safe(Token![safe]),
+}
+
+// extern "C"
+#[derive(Debug, Print, Walk)]
+pub struct Extern {
+    pub kw: Token![extern],
+    pub abi: Option<(Trivia, Literal)>,
+}
+
+// const async unsafe extern "C"
+#[derive(Debug, Print, Walk)]
+pub struct FnQualifiers {
+    pub const_: Option<(Token![const], Trivia)>,
+    pub async_: Option<(Token![async], Trivia)>,
+    pub safety: Option<(Safety, Trivia)>,
+    pub extern_: Option<(Extern, Trivia)>,
+}
+
+impl Respace for FnQualifiers {
+    fn respace(&mut self, _: &mut Spaces) {
+        let FnQualifiers { const_, async_, safety, extern_ } = self;
+        if let Some((_, t)) = const_ {
+            s1(t);
+        }
+        if let Some((_, t)) = async_ {
+            s1(t);
+        }
+        if let Some((_, t)) = safety {
+            s1(t);
+        }
+        if let Some((Extern { kw: _, abi }, t)) = extern_ {
+            if let Some((t, _)) = abi {
+                s1(t);
+            }
+            s1(t);
+        }
+    }
+}
+
 #[derive(Debug, Print, Walk)]
 pub struct Fn {
     pub vis: Option<(Visibility, Trivia)>,
+    pub qualifiers: FnQualifiers,
     pub kw: Token![fn],
     pub t1: TriviaN,
     pub name: Ident,
+    pub generics: Option<(Trivia, Generics)>,
     pub t2: Trivia,
     pub params: Parens<(Trivia, List<FnParam>)>,
     pub ret: Option<(Trivia, FnRet)>,
+    pub where_: Option<(Trivia, WhereClause)>,
     pub t3: Trivia,
-    pub block: Block,
+    pub body: FnBody,
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum FnBody {
+    Block(Block),
+    // fn f();
+    Semi(Token![;]),
 }
 
 // pub const NAME: Ty = expr;
@@ -120,23 +232,33 @@ pub struct Const {
     #[sourcery(spaces = 1)]
     pub t3: Trivia,
     pub ty: Ty,
+    // optional for associated consts
+    pub init: Option<ItemInit>,
+    #[sourcery(spaces = 0)]
+    pub t6: Trivia,
+    pub semi: Semi,
+}
+
+// = expr
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ItemInit {
     #[sourcery(spaces = 1)]
     pub t4: Trivia,
     pub eq: Token![=],
     #[sourcery(spaces = 1)]
     pub t5: Trivia,
     pub expr: Expr,
-    #[sourcery(spaces = 0)]
-    pub t6: Trivia,
-    pub semi: Semi,
 }
 
 #[derive(Debug, Print, Walk, Respace)]
 pub struct Static {
     pub vis: Option<(Visibility, Trivia)>,
+    // only meaningful inside extern blocks
+    pub safety: Option<(Safety, Trivia)>,
     pub kw: Token![static],
     #[sourcery(spaces = 1)]
     pub t1: TriviaN,
+    pub mut_: Option<(Token![mut], TriviaN)>,
     pub name: Ident,
     #[sourcery(spaces = 0)]
     pub t2: Trivia,
@@ -144,14 +266,353 @@ pub struct Static {
     #[sourcery(spaces = 1)]
     pub t3: Trivia,
     pub ty: Ty,
-    #[sourcery(spaces = 1)]
-    pub t4: Trivia,
-    pub eq: Token![=],
-    #[sourcery(spaces = 1)]
-    pub t5: Trivia,
-    pub expr: Expr,
+    // optional inside extern blocks
+    pub init: Option<ItemInit>,
     #[sourcery(spaces = 0)]
     pub t6: Trivia,
     pub semi: Semi,
 }
 
+impl Respace for Option<(Safety, Trivia)> {
+    fn respace(&mut self, _: &mut Spaces) {
+        if let Some((_, t)) = self {
+            s1(t);
+        }
+    }
+}
+
+impl Respace for Option<(Token![mut], TriviaN)> {
+    fn respace(&mut self, _: &mut Spaces) {
+        if let Some((_, t)) = self {
+            s1(t);
+        }
+    }
+}
+
+// extern crate name as rename;
+#[derive(Debug, Print, Walk)]
+pub struct ExternCrate {
+    pub vis: Option<(Visibility, Trivia)>,
+    pub kw: Token![extern],
+    pub t1: Trivia,
+    pub crate_: Token![crate],
+    pub t2: Trivia,
+    pub name: Ident,
+    pub rename: Option<(Trivia, Token![as], Trivia, Ident)>,
+    pub t3: Trivia,
+    pub semi: Token![;],
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum ForeignItemKind {
+    Fn(Fn),
+    Static(Static),
+    // type Opaque;
+    Type(TyAlias),
+    MacCall(MacroCall, Option<(Trivia, Token![;])>),
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct ForeignItem {
+    pub attrs: List<Attribute>,
+    pub kind: ForeignItemKind,
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct ForeignItems {
+    pub t1: Trivia,
+    pub attrs: List<Attribute>,
+    pub items: List<ForeignItem>,
+}
+
+// unsafe extern "C" { .. }
+#[derive(Debug, Print, Walk)]
+pub struct ForeignMod {
+    pub unsafe_: Option<(Token![unsafe], Trivia)>,
+    pub extern_: Extern,
+    pub t1: Trivia,
+    pub items: Braces<ForeignItems>,
+}
+
+
+// #[attr] pub name: Ty
+#[derive(Debug, Print, Walk)]
+pub struct NamedField {
+    pub attrs: List<Attribute>,
+    pub vis: Option<(Visibility, Trivia)>,
+    pub name: Ident,
+    pub t1: Trivia,
+    pub colon: Token![:],
+    pub t2: Trivia,
+    pub ty: Ty,
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct NamedFields {
+    pub t1: Trivia,
+    pub fields: SeparatedList<NamedField, Token![,]>,
+}
+
+// #[attr] pub Ty
+#[derive(Debug, Print, Walk)]
+pub struct TupleField {
+    pub attrs: List<Attribute>,
+    pub vis: Option<(Visibility, Trivia)>,
+    pub ty: Ty,
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct TupleFields {
+    pub t1: Trivia,
+    pub fields: SeparatedList<TupleField, Token![,]>,
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum Fields {
+    Named(Braces<NamedFields>),
+    Tuple(Parens<TupleFields>),
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum StructBody {
+    // where T: Trait { a: T }
+    Named {
+        where_: Option<(Trivia, WhereClause)>,
+        t2: Trivia,
+        fields: Braces<NamedFields>,
+    },
+    // (T) where T: Trait;
+    Tuple {
+        t2: Trivia,
+        fields: Parens<TupleFields>,
+        where_: Option<(Trivia, WhereClause)>,
+        t3: Trivia,
+        semi: Token![;],
+    },
+    // where T: Trait;
+    Unit {
+        where_: Option<(Trivia, WhereClause)>,
+        t2: Trivia,
+        semi: Token![;],
+    },
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct Struct {
+    pub vis: Option<(Visibility, Trivia)>,
+    pub kw: Token![struct],
+    pub t1: TriviaN,
+    pub name: Ident,
+    pub generics: Option<(Trivia, Generics)>,
+    pub body: StructBody,
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct Union {
+    pub vis: Option<(Visibility, Trivia)>,
+    pub kw: Token![union],
+    pub t1: TriviaN,
+    pub name: Ident,
+    pub generics: Option<(Trivia, Generics)>,
+    pub where_: Option<(Trivia, WhereClause)>,
+    pub t2: Trivia,
+    pub fields: Braces<NamedFields>,
+}
+
+// #[attr] Name(T) = 1
+#[derive(Debug, Print, Walk)]
+pub struct Variant {
+    pub attrs: List<Attribute>,
+    pub vis: Option<(Visibility, Trivia)>,
+    pub name: Ident,
+    pub fields: Option<(Trivia, Fields)>,
+    pub discriminant: Option<(Trivia, Token![=], Trivia, Expr)>,
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct Variants {
+    pub t1: Trivia,
+    pub variants: SeparatedList<Variant, Token![,]>,
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct Enum {
+    pub vis: Option<(Visibility, Trivia)>,
+    pub kw: Token![enum],
+    pub t1: TriviaN,
+    pub name: Ident,
+    pub generics: Option<(Trivia, Generics)>,
+    pub where_: Option<(Trivia, WhereClause)>,
+    pub t2: Trivia,
+    pub variants: Braces<Variants>,
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum AssocItemKind {
+    Const(Const),
+    Type(TyAlias),
+    Fn(Fn),
+    MacCall(MacroCall, Option<(Trivia, Token![;])>),
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct AssocItem {
+    pub attrs: List<Attribute>,
+    pub default_: Option<(Token![default], Trivia)>,
+    pub kind: AssocItemKind,
+}
+
+// the contents of a trait or impl body
+#[derive(Debug, Print, Walk)]
+pub struct AssocItems {
+    pub t1: Trivia,
+    pub attrs: List<Attribute>,
+    pub items: List<AssocItem>,
+}
+
+// pub unsafe auto trait Name<T>: Super where T: Trait { .. }
+#[derive(Debug, Print, Walk)]
+pub struct Trait {
+    pub vis: Option<(Visibility, Trivia)>,
+    pub unsafe_: Option<(Token![unsafe], Trivia)>,
+    pub auto: Option<(Token![auto], Trivia)>,
+    pub kw: Token![trait],
+    pub t1: TriviaN,
+    pub name: Ident,
+    pub generics: Option<(Trivia, Generics)>,
+    pub bounds: Option<TypeBounds>,
+    pub where_: Option<(Trivia, WhereClause)>,
+    pub t2: Trivia,
+    pub items: Braces<AssocItems>,
+}
+
+// trait Name<T> = Bound + Bound where T: Trait;
+#[derive(Debug, Print, Walk)]
+pub struct TraitAlias {
+    pub vis: Option<(Visibility, Trivia)>,
+    pub kw: Token![trait],
+    pub t1: TriviaN,
+    pub name: Ident,
+    pub generics: Option<(Trivia, Generics)>,
+    pub t2: Trivia,
+    pub eq: Token![=],
+    pub t3: Trivia,
+    pub bounds: SeparatedList<TypeParamBound, Token![+]>,
+    pub where_: Option<(Trivia, WhereClause)>,
+    pub t4: Trivia,
+    pub semi: Token![;],
+}
+
+// const !Trait for
+#[derive(Debug, Print, Walk)]
+pub struct ImplOfTrait {
+    pub const_: Option<(Token![const], Trivia)>,
+    pub negative: Option<(Token![!], Trivia)>,
+    pub path: Path,
+    pub t1: Trivia,
+    pub for_: Token![for],
+    pub t2: Trivia,
+}
+
+impl Respace for ImplOfTrait {
+    fn respace(&mut self, v: &mut Spaces) {
+        let ImplOfTrait { const_, negative, path, t1, for_: _, t2 } = self;
+        if let Some((_, t)) = const_ {
+            s1(t);
+        }
+        if let Some((_, t)) = negative {
+            s0(t);
+        }
+        path.respace(v);
+        s1(t1);
+        s1(t2);
+    }
+}
+
+// unsafe impl<T> Trait for Ty where T: Trait { .. }
+#[derive(Debug, Print, Walk)]
+pub struct Impl {
+    pub default_: Option<(Token![default], Trivia)>,
+    pub unsafe_: Option<(Token![unsafe], Trivia)>,
+    pub const_: Option<(Token![const], Trivia)>,
+    pub kw: Token![impl],
+    pub generics: Option<(Trivia, Generics)>,
+    pub t1: Trivia,
+    pub of_trait: Option<ImplOfTrait>,
+    pub self_ty: Ty,
+    pub where_: Option<(Trivia, WhereClause)>,
+    pub t2: Trivia,
+    pub items: Braces<AssocItems>,
+}
+
+// only the header, the items are visited separately
+impl Respace for Impl {
+    fn respace(&mut self, v: &mut Spaces) {
+        let Impl { default_, unsafe_, const_, kw: _, generics, t1, of_trait, self_ty, where_, t2, items: _ } = self;
+        if let Some((_, t)) = default_ {
+            s1(t);
+        }
+        if let Some((_, t)) = unsafe_ {
+            s1(t);
+        }
+        if let Some((_, t)) = const_ {
+            s1(t);
+        }
+        if let Some((t, generics)) = generics {
+            s0(t);
+            generics.respace(v);
+        }
+        s1(t1);
+        of_trait.respace(v);
+        self_ty.respace(v);
+        if let Some((t, where_)) = where_ {
+            s1(t);
+            where_.respace(v);
+        }
+        s1(t2);
+    }
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct UseGroup {
+    pub t1: Trivia,
+    pub trees: SeparatedList<UseTree, Token![,]>,
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum UseTree {
+    // a::tree
+    Path {
+        ident: Ident,
+        t1: Trivia,
+        colon2: Token![::],
+        t2: Trivia,
+        tree: Box<UseTree>,
+    },
+    // a
+    Name(Ident),
+    // a as b, a as _
+    Rename {
+        ident: Ident,
+        t1: Trivia,
+        as_: Token![as],
+        t2: Trivia,
+        rename: Ident,
+    },
+    // *
+    Glob(Token![*]),
+    // {a, b::c}
+    Group(Braces<UseGroup>),
+}
+
+// pub use ::a::{b, c as d};
+#[derive(Debug, Print, Walk)]
+pub struct Use {
+    pub vis: Option<(Visibility, Trivia)>,
+    pub kw: Token![use],
+    pub t1: Trivia,
+    pub leading_colon: Option<(Token![::], Trivia)>,
+    pub tree: UseTree,
+    pub t2: Trivia,
+    pub semi: Token![;],
+}
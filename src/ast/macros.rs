@@ -18,3 +18,108 @@ impl Respace for MacroCall {
         s1(t2);
     }
 }
+
+/// `$name:frag` in a macro matcher.
+#[derive(Debug, Print, Walk)]
+pub struct MetaVar {
+    pub dollar: Token![$],
+    pub t1: Trivia,
+    pub name: Ident,
+    pub t2: Trivia,
+    pub colon: Token![:],
+    pub t3: Trivia,
+    pub frag: Ident,
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum RepOp {
+    ZeroOrMore(Token![*]),
+    OneOrMore(Token![+]),
+    ZeroOrOne(Token![?]),
+}
+
+/// `$( .. ) sep rep` in a macro matcher.
+#[derive(Debug, Print, Walk)]
+pub struct Repetition {
+    pub dollar: Token![$],
+    pub t1: Trivia,
+    pub inner: Delimited<Matcher>,
+    pub sep: Option<(Trivia, TokenTree)>,
+    pub t2: Trivia,
+    pub op: RepOp,
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum MatcherTree {
+    Token(TokenTree),
+    Group(Delimited<Matcher>),
+    MetaVar(MetaVar),
+    Repetition(Repetition),
+}
+
+/// The token trees of a macro matcher, like [`TokenStream`] but with
+/// metavariables and repetitions picked out.
+#[derive(Debug, Print, Walk)]
+pub struct Matcher {
+    pub t1: Trivia,
+    pub tokens: List<MatcherTree>,
+}
+
+/// `(matcher) => { transcriber }`
+#[derive(Debug, Print, Walk)]
+pub struct MacroRule {
+    pub matcher: Delimited<Matcher>,
+    pub t1: Trivia,
+    pub arrow: Token![=>],
+    pub t2: Trivia,
+    pub transcriber: Delimited<TokenStream>,
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct MacroRulesBody {
+    pub t1: Trivia,
+    pub rules: SeparatedList<MacroRule, Token![;]>,
+}
+
+/// `macro_rules! name { .. }`
+#[derive(Debug, Print, Walk)]
+pub struct MacroRules {
+    pub kw: Token![macro_rules],
+    pub t1: Trivia,
+    pub bang: Token![!],
+    pub t2: Trivia,
+    pub name: Ident,
+    pub t3: Trivia,
+    pub body: Delimited<MacroRulesBody>,
+    pub semi: Option<(Trivia, Token![;])>,
+}
+
+/// The rules of a `macro`, separated by commas rather than semicolons.
+#[derive(Debug, Print, Walk)]
+pub struct Macro2Rules {
+    pub t1: Trivia,
+    pub rules: SeparatedList<MacroRule, Token![,]>,
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum Macro2Body {
+    /// `macro m($x:expr) { .. }`
+    Single {
+        matcher: Parens<Matcher>,
+        t1: Trivia,
+        transcriber: Braces<TokenStream>,
+    },
+    /// `macro m { ($x:expr) => { .. }, }`
+    Rules(Braces<Macro2Rules>),
+}
+
+/// `pub macro name { .. }`
+#[derive(Debug, Print, Walk)]
+pub struct Macro2 {
+    pub vis: Option<(Visibility, Trivia)>,
+    pub kw: Token![macro],
+    pub t1: TriviaN,
+    pub name: Ident,
+    pub t2: Trivia,
+    pub body: Macro2Body,
+}
@@ -1,5 +1,7 @@
 use std::fmt::Debug;
 
+use smol_str::SmolStr;
+
 mod attr;
 pub use attr::{Attribute, AttributeInner, AttributeStyle, AttributeValue};
 mod expr;
@@ -10,15 +12,23 @@ pub use token::*;
 mod item;
 pub use item::*;
 mod ty;
-pub use ty::{Ty, TyArray, TySlice};
+pub use ty::{
+    BareFnParam, BareFnParamKind, BareFnParams, PtrMutability, Ty, TyArray, TyBareFn, TyImplTrait,
+    TyParen, TyPtr, TyRef, TyRefKind, TySlice, TyTraitObject, TyTuple, TyUnsafeBinder,
+};
 mod stmt;
 pub use stmt::*;
 mod pat;
-pub use pat::Pat;
+pub use pat::{
+    Pat, PatBox, PatGuard, PatIdent, PatLit, PatOr, PatParen, PatRange, PatRef, PatSlice, PatStruct,
+    PatStructField, PatStructFields, PatTuple, PatTupleStruct,
+};
 mod path;
 pub use path::*;
 mod macros;
 pub use macros::*;
+mod generics;
+pub use generics::*;
 
 
 use crate::prelude::*;
@@ -97,6 +107,10 @@ impl<T> List<T> {
         self.tlast.extend(t);
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.inner.iter().map(|(x, _)| x)
+    }
+
     pub fn into_parts(self) -> (Vec<(T, Trivia)>, Trivia) {
         (self.inner, self.tlast)
     }
@@ -139,11 +153,21 @@ pub struct SeparatedList<T, S> {
     tlast: Trivia,
 }
 
+impl<T, S> Default for SeparatedList<T, S> {
+    fn default() -> Self {
+        SeparatedList::new()
+    }
+}
+
 impl<T, S> SeparatedList<T, S> {
     pub fn new() -> SeparatedList<T, S> {
         SeparatedList { inner: SeparatedListInner::Empty, tlast: Trivia::default() }
     }
 
+    pub fn is_empty(&self) -> bool {
+        matches!(self.inner, SeparatedListInner::Empty) && self.tlast.is_empty()
+    }
+
     pub fn new_single(x: T) -> SeparatedList<T, S> {
         SeparatedList { inner: SeparatedListInner::NonEmpty { first: Box::new(x), rest: Vec::new(), trailing: None }, tlast: Trivia::default() }
     }
@@ -182,6 +206,12 @@ pub struct SeparatedListBuilder<T, S> {
     l: SeparatedList<T, S>
 }
 
+impl<T, S> Default for SeparatedListBuilder<T, S> {
+    fn default() -> Self {
+        SeparatedListBuilder::new()
+    }
+}
+
 impl<T, S> SeparatedListBuilder<T, S> {
     pub fn new() -> Self {
         Self { t1: Trivia::default(), l: SeparatedList::new() }
@@ -232,6 +262,57 @@ impl<T: Visit, S: Visit> Walk for SeparatedList<T, S> {
     }
 }
 
+impl<T: Respace> Respace for SeparatedList<T, Token![,]> {
+    fn respace(&mut self, v: &mut Spaces) {
+        if let SeparatedListInner::NonEmpty { first, rest, trailing } = &mut self.inner {
+            first.respace(v);
+            for (t, _, t2, x) in rest {
+                s0(t);
+                s1(t2);
+                x.respace(v);
+            }
+            if let Some(L(t, _)) = trailing {
+                s0(t);
+            }
+        }
+        s0(&mut self.tlast);
+    }
+}
+
+impl<T: Respace> Respace for SeparatedList<T, Token![+]> {
+    fn respace(&mut self, v: &mut Spaces) {
+        if let SeparatedListInner::NonEmpty { first, rest, trailing } = &mut self.inner {
+            first.respace(v);
+            for (t, _, t2, x) in rest {
+                s1(t);
+                s1(t2);
+                x.respace(v);
+            }
+            if let Some(L(t, _)) = trailing {
+                s1(t);
+            }
+        }
+        s0(&mut self.tlast);
+    }
+}
+
+impl<T: Respace> Respace for SeparatedList<T, Token![|]> {
+    fn respace(&mut self, v: &mut Spaces) {
+        if let SeparatedListInner::NonEmpty { first, rest, trailing } = &mut self.inner {
+            first.respace(v);
+            for (t, _, t2, x) in rest {
+                s1(t);
+                s1(t2);
+                x.respace(v);
+            }
+            if let Some(L(t, _)) = trailing {
+                s1(t);
+            }
+        }
+        s0(&mut self.tlast);
+    }
+}
+
 impl<T: Debug, S: Debug> Debug for SeparatedList<T, S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut f = f.debug_list();
@@ -270,7 +351,7 @@ pub enum Visibility {
     Restricted {
         pub_: Token![pub],
         t1: Trivia,
-        parens: Parens<VisRestricted>,
+        parens: Box<Parens<VisRestricted>>,
     },
 }
 
@@ -281,8 +362,19 @@ pub struct Module {
     pub items: List<Item>,
 }
 
+// #!/usr/bin/env -S cargo +nightly -Zscript
+#[derive(Debug, Print)]
+pub struct Shebang(pub SmolStr);
+
+// ---cargo
+// [dependencies]
+// ---
+#[derive(Debug, Print)]
+pub struct Frontmatter(pub SmolStr);
+
 #[derive(Debug, Print, Walk)]
 pub struct File {
-    // shebang, frontmatter
+    pub shebang: Option<Shebang>,
+    pub frontmatter: Option<L<Frontmatter>>,
     pub module: Module,
 }
@@ -1,8 +1,247 @@
 use sourcery_derive::{Respace, Walk};
 
-use crate::ast::{Ident, Print};
+use crate::ast::{
+    Attribute, Braces, Brackets, ConstBlock, Expr, Ident, List, Literal, MacroCall, Member, Parens, Print,
+    QPath, RangeLimits, SeparatedList, Token, Trivia, TriviaN, L,
+};
+use crate::passes::style::spaces::{s0, s1, Respace, Spaces};
+
+// ref mut x @ Some(_)
+#[derive(Debug, Print, Walk)]
+pub struct PatIdent {
+    pub by_ref: Option<(Token![ref], TriviaN)>,
+    pub mut_: Option<(Token![mut], TriviaN)>,
+    pub ident: Ident,
+    pub sub: Option<(Trivia, Token![@], Trivia, Box<Pat>)>,
+}
+
+impl Respace for PatIdent {
+    fn respace(&mut self, v: &mut Spaces) {
+        let PatIdent { by_ref, mut_, ident: _, sub } = self;
+        if let Some((_, t)) = by_ref {
+            s1(t);
+        }
+        if let Some((_, t)) = mut_ {
+            s1(t);
+        }
+        if let Some((t1, _, t2, pat)) = sub {
+            s1(t1);
+            s1(t2);
+            pat.respace(v);
+        }
+    }
+}
+
+// 1, -1 or b'a'
+#[derive(Debug, Print, Walk)]
+pub struct PatLit {
+    pub neg: Option<(Token![-], Trivia)>,
+    pub lit: Literal,
+}
+
+impl Respace for PatLit {
+    fn respace(&mut self, _: &mut Spaces) {
+        if let Some((_, t)) = &mut self.neg {
+            s0(t);
+        }
+    }
+}
+
+// 1..=9, A.., ..=Z and ..Z
+#[derive(Debug, Print, Walk)]
+pub struct PatRange {
+    pub start: Option<(Box<Pat>, Trivia)>,
+    pub limits: RangeLimits,
+    pub end: Option<L<Box<Pat>>>,
+}
+
+impl Respace for PatRange {
+    fn respace(&mut self, v: &mut Spaces) {
+        let PatRange { start, limits: _, end } = self;
+        if let Some((start, t)) = start {
+            start.respace(v);
+            s0(t);
+        }
+        if let Some(L(t, end)) = end {
+            s0(t);
+            end.respace(v);
+        }
+    }
+}
+
+// | A | B
+#[derive(Debug, Print, Walk)]
+pub struct PatOr {
+    pub vert: Option<(Token![|], Trivia)>,
+    pub pats: SeparatedList<Pat, Token![|]>,
+}
+
+impl Respace for PatOr {
+    fn respace(&mut self, v: &mut Spaces) {
+        if let Some((_, t)) = &mut self.vert {
+            s1(t);
+        }
+        self.pats.respace(v);
+    }
+}
+
+// x if x > 0
+#[derive(Debug, Print, Walk, Respace)]
+pub struct PatGuard {
+    pub pat: Box<Pat>,
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub if_: Token![if],
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub cond: Box<Expr>,
+}
+
+// &pat or &mut pat
+#[derive(Debug, Print, Walk)]
+pub struct PatRef {
+    pub and: Token![&],
+    pub mut_: Option<(Trivia, Token![mut])>,
+    pub t1: Trivia,
+    pub pat: Box<Pat>,
+}
+
+impl Respace for PatRef {
+    fn respace(&mut self, v: &mut Spaces) {
+        let PatRef { and: _, mut_, t1, pat } = self;
+        if let Some((t, _)) = mut_ {
+            s0(t);
+            s1(t1);
+        } else {
+            s0(t1);
+        }
+        pat.respace(v);
+    }
+}
+
+// box pat
+#[derive(Debug, Print, Walk, Respace)]
+pub struct PatBox {
+    pub kw: Token![box],
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub pat: Box<Pat>,
+}
+
+// (a, b), (a,) and ()
+#[derive(Debug, Print, Walk, Respace)]
+pub struct PatTuple {
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub pats: SeparatedList<Pat, Token![,]>,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct PatParen {
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub pat: Box<Pat>,
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+}
+
+// [a, .., b]
+#[derive(Debug, Print, Walk, Respace)]
+pub struct PatSlice {
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub pats: SeparatedList<Pat, Token![,]>,
+}
+
+// Some(x)
+#[derive(Debug, Print, Walk, Respace)]
+pub struct PatTupleStruct {
+    pub qpath: QPath,
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub fields: Parens<PatTuple>,
+}
+
+// `x`, `0: a` or `#[cfg(a)] x: b`
+#[derive(Debug, Print, Walk)]
+pub struct PatStructField {
+    pub attrs: List<Attribute>,
+    pub member: Option<(Member, Trivia, Token![:], Trivia)>,
+    pub pat: Pat,
+}
+
+impl Respace for PatStructField {
+    fn respace(&mut self, v: &mut Spaces) {
+        let PatStructField { attrs, member, pat } = self;
+        attrs.respace(v);
+        if let Some((member, t1, _, t2)) = member {
+            member.respace(v);
+            s0(t1);
+            s1(t2);
+        }
+        pat.respace(v);
+    }
+}
+
+#[derive(Debug, Print, Walk)]
+pub struct PatStructFields {
+    pub t1: Trivia,
+    pub fields: SeparatedList<PatStructField, Token![,]>,
+    pub dot2: Option<L<Token![..]>>,
+    pub tlast: Trivia,
+}
+
+impl Respace for PatStructFields {
+    fn respace(&mut self, v: &mut Spaces) {
+        let PatStructFields { t1, fields, dot2, tlast } = self;
+        fields.respace(v);
+        if fields.is_empty() {
+            s0(t1);
+        } else {
+            s1(t1);
+        }
+        if let Some(L(t, _)) = dot2 {
+            s1(t);
+        }
+        if fields.is_empty() && dot2.is_none() {
+            s0(tlast);
+        } else {
+            s1(tlast);
+        }
+    }
+}
+
+// Point { x, y: 0, .. }
+#[derive(Debug, Print, Walk, Respace)]
+pub struct PatStruct {
+    pub qpath: QPath,
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub fields: Braces<PatStructFields>,
+}
 
 #[derive(Debug, Print, Walk, Respace)]
 pub enum Pat {
-    Ident(Ident),
-}
\ No newline at end of file
+    Ident(PatIdent),
+    Lit(PatLit),
+    Range(PatRange),
+    Or(PatOr),
+    // _
+    Wild(Token![_]),
+    // ..
+    Rest(Token![..]),
+    Ref(PatRef),
+    Box(PatBox),
+    Tuple(Parens<PatTuple>),
+    Paren(Parens<PatParen>),
+    Slice(Brackets<PatSlice>),
+    // None, Self::A or <T as Trait>::A
+    Path(QPath),
+    TupleStruct(PatTupleStruct),
+    Struct(PatStruct),
+    ConstBlock(ConstBlock),
+    MacCall(MacroCall),
+    // !
+    Never(Token![!]),
+    Guard(PatGuard),
+}
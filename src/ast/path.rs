@@ -4,7 +4,45 @@ use crate::prelude::*;
 pub enum GenericArg {
     Lifetime(Ident),
     Type(Ty),
-    // todo: Const, AssocType, AssocConst, Bound
+    // 3, -1 or { N + 1 }
+    Const(Expr),
+    // Item = u8
+    AssocType(AssocType),
+    // N = 3
+    AssocConst(AssocConst),
+    // Assoc: Send
+    Bound(AssocBound),
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct AssocType {
+    pub ident: Ident,
+    pub args: Option<L<PathSegmentArgs>>,
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub eq: Token![=],
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub ty: Ty,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct AssocConst {
+    pub ident: Ident,
+    pub args: Option<L<PathSegmentArgs>>,
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub eq: Token![=],
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub expr: Expr,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct AssocBound {
+    pub ident: Ident,
+    pub args: Option<L<PathSegmentArgs>>,
+    pub bounds: TypeBounds,
 }
 
 #[derive(Debug, Print, Walk, Respace)]
@@ -13,21 +51,57 @@ pub struct PathSegment {
     pub args: Option<L<PathSegmentArgs>>
 }
 
+// (..)
+#[derive(Debug, Print, Walk, Respace)]
+pub struct ReturnTypeNotation {
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub dots: Token![..],
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+}
+
 #[derive(Debug, Print, Walk)]
 pub enum PathSegmentArgs {
+    // ::<T, U>
     AngleBracketed {
-        colon2: Option<L<Token![::]>>,
-        t1: Trivia,
+        colon2: Option<(Token![::], Trivia)>,
         lt: Token![<],
-        t2: Trivia,
+        t1: Trivia,
         args: SeparatedList<GenericArg, Token![,]>,
         gt: Token![>],
-    }
+    },
+    // (A, B) -> C
+    Parenthesized {
+        inputs: Parens<TyTuple>,
+        ret: Option<(Trivia, Box<FnRet>)>,
+    },
+    // f(..)
+    ReturnType(Parens<ReturnTypeNotation>),
 }
 
 impl Respace for L<PathSegmentArgs> {
-    fn respace(&mut self, _: &mut Spaces) {
-        todo!()
+    fn respace(&mut self, v: &mut Spaces) {
+        let L(t0, args) = self;
+        s0(t0);
+        match args {
+            PathSegmentArgs::AngleBracketed { colon2, lt: _, t1, args, gt: _ } => {
+                if let Some((_, t)) = colon2 {
+                    s0(t);
+                }
+                s0(t1);
+                args.respace(v);
+            }
+            PathSegmentArgs::Parenthesized { inputs, ret } => {
+                inputs.respace(v);
+                if let Some((t, ret)) = ret {
+                    s1(t);
+                    s1(&mut ret.t2_5);
+                    ret.ty.respace(v);
+                }
+            }
+            PathSegmentArgs::ReturnType(rtn) => rtn.respace(v),
+        }
     }
 }
 
@@ -70,19 +144,32 @@ pub struct QSelf {
     pub right: Token![>],
 }
 
+impl Respace for QSelf {
+    fn respace(&mut self, v: &mut Spaces) {
+        let QSelf { left: _, t1, ty, as_, tlast, right: _ } = self;
+        s0(t1);
+        ty.respace(v);
+        if let Some((t1, _, t2, path)) = as_ {
+            s1(t1);
+            s1(t2);
+            path.respace(v);
+        }
+        s0(tlast);
+    }
+}
+
 #[derive(Debug, Print, Walk)]
 pub struct QPath {
-    pub qself: Option<(QSelf, Trivia)>,
+    pub qself: Option<(Box<QSelf>, Trivia)>,
     pub path: Path,
 }
 
 impl Respace for QPath {
     fn respace(&mut self, v: &mut Spaces) {
-        if self.qself.is_some() {
-            todo!()
+        if let Some((qself, t)) = &mut self.qself {
+            qself.respace(v);
+            s0(t);
         }
         self.path.respace(v);
     }
 }
-
-
@@ -9,6 +9,7 @@ pub struct Stmt {
 #[derive(Debug, Print, Walk)]
 pub struct BlockInner {
     pub t0: Trivia,
+    pub attrs: List<Attribute>,
     pub stmts: List<Stmt>,
 }
 
@@ -38,15 +39,45 @@ impl Visit for LabeledBlock {
     }
 }
 
+#[derive(Debug, Print, Walk)]
+pub struct LocalInit {
+    pub t2: Trivia,
+    pub eq: Token![=],
+    pub t3: Trivia,
+    pub expr: Box<Expr>,
+    pub else_: Option<(Trivia, Token![else], Trivia, Block)>,
+}
+
+// let pat: Ty = expr else { .. };
+#[derive(Debug, Print, Walk)]
+pub struct Local {
+    pub token: Token![let],
+    pub t1: Trivia,
+    pub pat: Pat,
+    pub ty: Option<(Trivia, Token![:], Trivia, Ty)>,
+    pub init: Option<LocalInit>,
+    pub t4: Trivia,
+    pub semi: Token![;],
+}
+
 #[derive(Debug, Print, Walk)]
 pub enum StmtKind {
     Empty(Token![;]),
+    Local(Local),
     Semi(Expr, Trivia, Token![;]),
     Expr(Expr),
+    Item(Item),
+    // `foo!(..);` or `foo! { .. }`, the semicolon is optional only for braces
+    // or at the end of a block
+    MacCall(MacroCall, Option<(Trivia, Token![;])>),
 }
 
+// statements are not respaced yet, so only empty blocks are touched
 impl Respace for Block {
     fn respace(&mut self, _: &mut Spaces) {
-        todo!()
+        let BlockInner { t0, attrs, stmts } = &mut self.0;
+        if attrs.is_empty() && stmts.is_empty() {
+            s0(t0);
+        }
     }
 }
@@ -294,7 +294,9 @@ define_tokens! {
     keywords(
         Mod(mod), Pub(pub), In(in), Type(type), Fn(fn), Const(const), Static(static), Unsafe(unsafe), Async(async),
         Try(try), As(as), If(if), Else(else), While(while), Loop(loop), For(for), Match(match), Break(break), Continue(continue),
-        Return(return), Yield(yield), Become(become), Let(let),
+        Return(return), Yield(yield), Become(become), Let(let), Mut(mut), Raw(raw), Await(await), Where(where),
+        Struct(struct), Enum(enum), Union(union), Trait(trait), Auto(auto),
+        Impl(impl), Default(default), SelfLower(self), Safe(safe), Extern(extern), Use(use), Crate(crate), MacroRules(macro_rules), Macro(macro), Underscore(_), Dyn(dyn), Pin(pin), Box(box), Ref(ref), Move(move), Gen(gen), Do(do), Yeet(yeet), Builtin(builtin),
     );
     tokens(
         Semi(;),
@@ -312,8 +314,8 @@ define_tokens! {
         Dollar($),
         Eq(=), EqEq(==),
         Bang(!), BangEq(!=),
-        Lt(<), LtEq(<=), LtLtEq(<<=),
-        Gt(>), GtEq(>=), GtGtEq(>>=),
+        Lt(<), LtEq(<=), LtLt(<<), LtLtEq(<<=),
+        Gt(>), GtEq(>=), GtGt(>>), GtGtEq(>>=),
         Minus(-), MinusEq(-=),
         And(&), AndAnd(&&), AndEq(&=),
         Or(|), OrOr(||), OrEq(|=),
@@ -336,16 +338,50 @@ impl Debug for Ident {
 }
 
 // when updating this enum, also update glue.rs starting from `use ra_ap_rustc_lexer::LiteralKind as K;``
-#[derive(Debug, Clone, Copy, Print)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LiteralKind {
+    // 12_u8, 0o100 or 1f32
     Int,
+    // 12.34f32 or 1e3
     Float,
-    Other,
+    // 'a'
+    Char,
+    // b'a'
+    Byte,
+    // "abc"
+    Str,
+    // b"abc"
+    ByteStr,
+    // c"abc"
+    CStr,
+    // r#"abc"#
+    RawStr { hashes: u8 },
+    // br#"abc"#
+    RawByteStr { hashes: u8 },
+    // cr#"abc"#
+    RawCStr { hashes: u8 },
 }
 
-#[derive(Debug, Clone, Print)]
+#[derive(Debug, Clone)]
 pub struct Literal {
     pub kind: LiteralKind,
     pub symbol: SmolStr,
     pub suffix: SmolStr,
 }
+
+impl Print for Literal {
+    fn print(&self, dest: &mut String) {
+        self.symbol.print(dest);
+        self.suffix.print(dest);
+    }
+}
+
+// #"abc"#, reserved since edition 2024 and only found in macro input
+#[derive(Clone, Print)]
+pub struct GuardedStr(pub SmolStr);
+
+impl Debug for GuardedStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
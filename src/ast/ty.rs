@@ -1,6 +1,11 @@
 use sourcery_derive::{Respace, Walk};
 
-use crate::ast::{Brackets, Expr, QPath, Token, Trivia};
+use crate::ast::{
+    Attribute, BoundLifetimes, Brackets, Expr, Extern, FnRet, Generics, Ident, List, MacroCall, Parens,
+    QPath, Safety,
+    SeparatedList, Token, Trivia, TypeParamBound,
+};
+use crate::passes::style::spaces::{s0, s1, Respace, Spaces};
 use crate::Print;
 
 // [Ty; N]
@@ -28,9 +33,200 @@ pub struct TySlice {
     pub tlast: Trivia,
 }
 
+#[derive(Debug, Print, Walk)]
+pub enum TyRefKind {
+    // &T
+    Ref,
+    // &mut T
+    Mut(Trivia, Token![mut]),
+    // &pin const T or &pin mut T
+    Pin(Trivia, Token![pin], Trivia, PtrMutability),
+}
+
+// &'a mut T
+#[derive(Debug, Print, Walk)]
+pub struct TyRef {
+    pub and: Token![&],
+    pub lifetime: Option<(Trivia, Ident)>,
+    pub kind: TyRefKind,
+    pub t1: Trivia,
+    pub ty: Box<Ty>,
+}
+
+impl Respace for TyRef {
+    fn respace(&mut self, v: &mut Spaces) {
+        let TyRef { and: _, lifetime, kind, t1, ty } = self;
+        if let Some((t, _)) = lifetime {
+            s0(t);
+        }
+        if let TyRefKind::Mut(t, _) | TyRefKind::Pin(t, ..) = kind {
+            if lifetime.is_some() {
+                s1(t);
+            } else {
+                s0(t);
+            }
+        }
+        if let TyRefKind::Pin(_, _, t, _) = kind {
+            s1(t);
+        }
+        if lifetime.is_none() && matches!(kind, TyRefKind::Ref) {
+            s0(t1);
+        } else {
+            s1(t1);
+        }
+        ty.respace(v);
+    }
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub enum PtrMutability {
+    Const(Token![const]),
+    Mut(Token![mut]),
+}
+
+// *const T
+#[derive(Debug, Print, Walk, Respace)]
+pub struct TyPtr {
+    pub star: Token![*],
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub mutability: PtrMutability,
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub ty: Box<Ty>,
+}
+
+// (A, B), (A,) and ()
+#[derive(Debug, Print, Walk, Respace)]
+pub struct TyTuple {
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub tys: SeparatedList<Ty, Token![,]>,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct TyParen {
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub ty: Box<Ty>,
+    #[sourcery(spaces = 0)]
+    pub t2: Trivia,
+}
+
+#[derive(Debug, Print, Walk)]
+pub enum BareFnParamKind {
+    // `name: T`, `_: T` or `T`
+    Typed {
+        name: Option<(Ident, Trivia, Token![:], Trivia)>,
+        ty: Box<Ty>,
+    },
+    Variadic(Token![...]),
+}
+
+impl Respace for BareFnParamKind {
+    fn respace(&mut self, v: &mut Spaces) {
+        if let BareFnParamKind::Typed { name, ty } = self {
+            if let Some((_, t1, _, t2)) = name {
+                s0(t1);
+                s1(t2);
+            }
+            ty.respace(v);
+        }
+    }
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct BareFnParam {
+    pub attrs: List<Attribute>,
+    pub kind: BareFnParamKind,
+}
+
+#[derive(Debug, Print, Walk, Respace)]
+pub struct BareFnParams {
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub params: SeparatedList<BareFnParam, Token![,]>,
+}
+
+// for<'a> unsafe extern "C" fn(u8, ...) -> u8
+#[derive(Debug, Print, Walk)]
+pub struct TyBareFn {
+    pub bound_lifetimes: Option<(BoundLifetimes, Trivia)>,
+    pub safety: Option<(Safety, Trivia)>,
+    pub extern_: Option<(Extern, Trivia)>,
+    pub kw: Token![fn],
+    pub t1: Trivia,
+    pub params: Parens<BareFnParams>,
+    pub ret: Option<(Trivia, FnRet)>,
+}
+
+impl Respace for TyBareFn {
+    fn respace(&mut self, v: &mut Spaces) {
+        let TyBareFn { bound_lifetimes, safety, extern_, kw: _, t1, params, ret } = self;
+        bound_lifetimes.respace(v);
+        if let Some((_, t)) = safety {
+            s1(t);
+        }
+        if let Some((Extern { kw: _, abi }, t)) = extern_ {
+            if let Some((t, _)) = abi {
+                s1(t);
+            }
+            s1(t);
+        }
+        s0(t1);
+        params.respace(v);
+        if let Some((t, FnRet { arrow: _, t2_5, ty })) = ret {
+            s1(t);
+            s1(t2_5);
+            ty.respace(v);
+        }
+    }
+}
+
+// dyn A + B + 'a
+#[derive(Debug, Print, Walk, Respace)]
+pub struct TyTraitObject {
+    pub kw: Token![dyn],
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub bounds: SeparatedList<TypeParamBound, Token![+]>,
+}
+
+// impl A + use<'a>
+#[derive(Debug, Print, Walk, Respace)]
+pub struct TyImplTrait {
+    pub kw: Token![impl],
+    #[sourcery(spaces = 1)]
+    pub t1: Trivia,
+    pub bounds: SeparatedList<TypeParamBound, Token![+]>,
+}
+
+// unsafe<'a> T
+#[derive(Debug, Print, Walk, Respace)]
+pub struct TyUnsafeBinder {
+    pub kw: Token![unsafe],
+    #[sourcery(spaces = 0)]
+    pub t1: Trivia,
+    pub generics: Generics,
+    #[sourcery(spaces = 1)]
+    pub t2: Trivia,
+    pub ty: Box<Ty>,
+}
+
 #[derive(Debug, Print, Walk, Respace)]
 pub enum Ty {
-    Path(QPath),
+    Path(Box<QPath>),
     Slice(Brackets<TySlice>),
-    Array(Brackets<TyArray>)
+    Array(Brackets<TyArray>),
+    Ref(TyRef),
+    Ptr(TyPtr),
+    Never(Token![!]),
+    Tuple(Parens<TyTuple>),
+    Paren(Parens<TyParen>),
+    Infer(Token![_]),
+    BareFn(Box<TyBareFn>),
+    TraitObject(TyTraitObject),
+    ImplTrait(TyImplTrait),
+    UnsafeBinder(TyUnsafeBinder),
+    MacCall(Box<MacroCall>),
 }
@@ -44,6 +44,18 @@ impl<'src> Lexer<'src> {
                     trivia.push(Trivium::BlockComment(snippet));
                     continue;
                 }
+                GuardedStrPrefix => {
+                    // only `#"` or `##` is eaten, lex the rest of the guarded string if there is
+                    // one, or give back everything but the first `#`
+                    let mut cursor = Cursor::new(&self.orig_str[start..], FrontmatterAllowed::No);
+                    let (kind, len) = match cursor.guarded_double_quoted_string() {
+                        Some(s) => (GuardedStrPrefix, s.token_len as usize),
+                        None => (Pound, 1),
+                    };
+                    self.cur_pos = start + len;
+                    self.inner = Cursor::new(&self.orig_str[self.cur_pos..], FrontmatterAllowed::No);
+                    (trivia, kind, SmolStr::new(&self.orig_str[start..self.cur_pos]))
+                }
                 Frontmatter { .. }
                 | Ident
                 | InvalidIdent
@@ -51,7 +63,6 @@ impl<'src> Lexer<'src> {
                 | UnknownPrefix
                 | UnknownPrefixLifetime
                 | RawLifetime
-                | GuardedStrPrefix
                 | Literal { .. }
                 | Lifetime { .. }
                 | Semi
@@ -88,10 +99,10 @@ impl<'src> Lexer<'src> {
     }
 }
 
-pub fn tokenize(s: &str) -> Lexer<'_> {
+pub fn tokenize(s: &str, frontmatter: FrontmatterAllowed) -> Lexer<'_> {
     Lexer {
         orig_str: s,
-        inner: Cursor::new(s, rustc_lexer::FrontmatterAllowed::Yes),
+        inner: Cursor::new(s, frontmatter),
         cur_pos: 0,
     }
 }
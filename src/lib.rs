@@ -16,7 +16,7 @@ pub(crate) mod prelude {
     pub use crate::passes::style::spaces::*;
 }
 
-pub use parse::{parse, parse_to_tokenstream};
+pub use parse::{parse, parse_to_tokenstream, parse_with_config, ParserConfig};
 pub use print::Print;
 
 pub use sourcery_derive::{Print, Walk};
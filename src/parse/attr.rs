@@ -44,14 +44,14 @@ impl<'src> Parser<'src> {
         ))
     }
     pub fn parse_attr_inner(&mut self) -> AttributeInner {
-        let L(t2, path) = self.parse_path();
+        let L(t2, path) = self.parse_path(PathStyle::Mod);
         let value = if let Some(t3) = self.eat_punct(Punct::Eq) {
             let L(t4, expr) = self.parse_expr();
             AttributeValue::Value {
                 t3,
                 eq: Token![=],
                 t4,
-                expr,
+                expr: Box::new(expr),
             }
         } else if let Some(L(val, tt)) = self.eat_delimited() {
             AttributeValue::List(val, tt)
@@ -1,10 +1,10 @@
-use smol_str::SmolStr;
-
 use crate::parse::attr::AttrKind;
 use crate::prelude::*;
 
 #[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
 pub enum Precedence {
+    // return, break, closures
+    Jump,
     Assign,
     Range,
     Or,
@@ -20,7 +20,26 @@ pub enum Precedence {
 }
 
 impl Precedence {
-    pub const MIN: Precedence = Precedence::Assign;
+    pub const MIN: Precedence = Precedence::Jump;
+
+    pub fn of_binop(op: &BinOp) -> Precedence {
+        match op {
+            BinOp::Add(_) | BinOp::Sub(_) => Precedence::Sum,
+            BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => Precedence::Product,
+            BinOp::And(_) => Precedence::And,
+            BinOp::Or(_) => Precedence::Or,
+            BinOp::BitXor(_) => Precedence::BitXor,
+            BinOp::BitAnd(_) => Precedence::BitAnd,
+            BinOp::BitOr(_) => Precedence::BitOr,
+            BinOp::Shl(_) | BinOp::Shr(_) => Precedence::Shift,
+            BinOp::Eq(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Ne(_)
+            | BinOp::Ge(_)
+            | BinOp::Gt(_) => Precedence::Compare,
+        }
+    }
 }
 
 impl<'src> super::Parser<'src> {
@@ -51,13 +70,10 @@ impl<'src> super::Parser<'src> {
             })
     }
     fn parse_expr_inner(&mut self, allow_struct: bool) -> L<Expr> {
-        let (t0, mut attrs) = self.parse_attrs(AttrKind::Outer).unwrap_or_default();
-        let L(t1, kind) = self.parse_atom_expr(allow_struct);
-        // TODO audit every usage of this. It is not semantically correct but it sure is convenient
-        attrs.push_trivia(t1);
-        t0 << Expr { attrs, kind }
+        self.parse_unary_expr(allow_struct)
+            .map(|lhs| self.parse_expr_finish(lhs, allow_struct, Precedence::MIN))
     }
-    fn choose_generics_over_qpath(&self) -> bool {
+    pub(super) fn choose_generics_over_qpath(&self) -> bool {
         self.check_punct(Punct::Lt)
             && (self.peek2(|tt| tt.is_punct(Punct::Gt))
                 || self.peek2(|tt| tt.is_punct(Punct::Pound))
@@ -77,8 +93,7 @@ impl<'src> super::Parser<'src> {
     }
     pub(super) fn parse_expr_with_earlier_boundary_rule(&mut self) -> L<Expr> {
         let (t0, mut attrs) = self.parse_attrs(AttrKind::Outer).unwrap_or_default();
-        // TODO audit every usage of this. It is not semantically correct but it sure is convenient
-        let L(t1, kind) = self
+        let block_like = self
             .parse_expr_if()
             .or_else(|| self.parse_expr_while())
             .or_else(|| {
@@ -87,26 +102,76 @@ impl<'src> super::Parser<'src> {
                     .flatten()
             })
             .or_else(|| self.parse_expr_loop())
-            // .or_else(|| self.parse_expr_match);
+            .or_else(|| self.parse_expr_match())
             .or_else(|| self.parse_try_block())
             .or_else(|| self.parse_unsafe_block())
             .or_else(|| self.parse_const_block())
-            .unwrap_or_else(|| {
+            .or_else(|| {
                 if self.peek(|tt| tt.is_delim(Delimiter::Braces)) {
-                    self.parse_block()
-                        .map(|block| ExprKind::Block(LabeledBlock { label: None, block }))
+                    Some(
+                        self.parse_block()
+                            .map(|block| ExprKind::Block(LabeledBlock { label: None, block })),
+                    )
                 } else if self.peek(|tt| matches!(tt, TokenTree::Lifetime(_))) {
-                    self.parse_labeled_atom_expr()
+                    Some(self.parse_labeled_atom_expr())
                 } else {
-                    self.parse_unary_expr_kind(true)
+                    None
                 }
             });
-        // TODO continue parsing
-        attrs.push_trivia(t1);
-        t0 << Expr { attrs, kind }
+        // TODO audit every usage of this. It is not semantically correct but it sure is convenient
+        if let Some(L(t1, mut kind)) = block_like {
+            attrs.push_trivia(t1);
+            // only method calls, field accesses and `?` may continue a block-like expression
+            if self.check_punct(Punct::Dot) || self.check_punct(Punct::Question) {
+                kind = self.parse_trailer_expr(kind);
+                return t0 << self.parse_expr_finish(Expr { attrs, kind }, true, Precedence::MIN);
+            }
+            t0 << Expr { attrs, kind }
+        } else {
+            let L(t1, kind) = self.parse_unary_expr_kind(true);
+            attrs.push_trivia(t1);
+            t0 << self.parse_expr_finish(Expr { attrs, kind }, true, Precedence::MIN)
+        }
+    }
+    /// Parses `async {}`, `gen {}` and `async gen {}`, each optionally followed by `move`.
+    /// The `gen` ones are nightly only.
+    fn parse_async_block(&mut self) -> Option<L<ExprKind>> {
+        let mut n = 0;
+        if self.check_ident("async") {
+            n += 1;
+        }
+        if self.config.nightly && self.peek_nth(n, |L(_, tt)| tt.is_ident("gen")) {
+            n += 1;
+        }
+        if n == 0 {
+            return None;
+        }
+        if self.peek_nth(n, |L(_, tt)| tt.is_ident("move")) {
+            n += 1;
+        }
+        if !self.peek_nth(n, |L(_, tt)| tt.is_delim(Delimiter::Braces)) {
+            return None;
+        }
+        let tasync = self.eat_kw("async");
+        let tgen = if self.config.nightly { self.eat_kw("gen") } else { None };
+        let (t0, kind, t1) = match (tasync, tgen) {
+            (Some(t0), Some(t)) => {
+                let kind = CoroutineKind::AsyncGen(Token![async], TriviaN::new(t), Token![gen]);
+                (t0, kind, self.eat_kw("move"))
+            }
+            (Some(t0), None) => (t0, CoroutineKind::Async(Token![async]), self.eat_kw("move")),
+            (None, Some(t0)) => (t0, CoroutineKind::Gen(Token![gen]), self.eat_kw("move")),
+            (None, None) => unreachable!(),
+        };
+        let L(tblock, block) = self.parse_block();
+        let (t1, move_) = match t1 {
+            Some(t1) => (t1, Some((Token![move], tblock))),
+            None => (tblock, None),
+        };
+        Some(t0 << ExprKind::AsyncBlock(AsyncBlock { kind, t1, move_, block }))
     }
     fn parse_try_block(&mut self) -> Option<L<ExprKind>> {
-        if self.check_ident("try") && self.peek2(|tt| tt.is_delim(Delimiter::Braces)) {
+        if self.config.nightly && self.check_ident("try") && self.peek2(|tt| tt.is_delim(Delimiter::Braces)) {
             let t = self.eat_ident("try").unwrap().0;
             let L(t1, block) = self.parse_block();
             Some(
@@ -156,10 +221,7 @@ impl<'src> super::Parser<'src> {
         let t2 = self.eat_punct(Punct::Eq).unwrap();
         let L(t3, expr) = self
             .parse_unary_expr(allow_struct)
-            .map(|mut exp| {
-                exp.kind = self.parse_expr_finish(exp.kind, allow_struct, Precedence::Compare);
-                exp
-            })
+            .map(|exp| self.parse_expr_finish(exp, allow_struct, Precedence::Compare))
             .map(Box::new);
         Some(
             t0 << ExprKind::Let(ExprLet {
@@ -235,8 +297,9 @@ impl<'src> super::Parser<'src> {
         }
     }
     fn parse_closure_args(&mut self) -> L<List<ClosureArg>> {
-        if self.check_punct(Punct::Or) {
-            return Trivia::default() << List::default();
+        // `||` is split so that an empty argument list is just two bars
+        if let Some(tlast) = self.eat_punct_split(Punct::Or) {
+            return tlast << List::default();
         }
         let L(t0, arg) = self.parse_closure_arg();
         let mut has_comma = arg.comma.is_some();
@@ -252,20 +315,65 @@ impl<'src> super::Parser<'src> {
             list.push(t, arg);
         }
     }
+    /// Whether the tokens ahead start a closure, possibly with `for<..>`, `static`, `async`
+    /// or `move` in front of it.
+    fn check_closure(&self) -> bool {
+        let mut n = 0;
+        if self.config.nightly && self.check_ident("for") && self.choose_generics_over_qpath_after_keyword() {
+            let mut this = self.snapshot();
+            this.parse_bound_lifetimes();
+            return this.check_closure();
+        }
+        for kw in ["static", "async", "move"] {
+            // `static` closures are coroutines
+            if (kw != "static" || self.config.nightly) && self.peek_nth(n, |L(_, tt)| tt.is_ident(kw)) {
+                n += 1;
+            }
+        }
+        self.peek_nth(n, |L(_, tt)| tt.is_punct(Punct::Or) || tt.is_punct(Punct::OrOr))
+    }
     fn parse_closure(&mut self, allow_struct: bool) -> Option<L<Closure>> {
-        let t0 = self.eat_punct(Punct::Or)?;
+        if !self.check_closure() {
+            return None;
+        }
+        let binder = self.parse_bound_lifetimes();
+        let tstatic = self.eat_kw("static");
+        let tasync = self.eat_kw("async");
+        let tmove = self.eat_kw("move");
+        let mut tnext = self.eat_punct_split(Punct::Or).unwrap();
+        // the trivia before each keyword trails the keyword preceding it
+        let move_ = tmove.map(|t| (Token![move], std::mem::replace(&mut tnext, t)));
+        let async_ = tasync.map(|t| (Token![async], std::mem::replace(&mut tnext, t)));
+        let static_ = tstatic.map(|t| (Token![static], std::mem::replace(&mut tnext, t)));
+        let binder = binder.map(|L(t, binder)| (binder, std::mem::replace(&mut tnext, t)));
         let L(t1, args) = self.parse_closure_args();
         let ret = self.parse_fn_ret();
-        let L(t2, body) = self.parse_expr_inner(allow_struct).map(Box::new);
+        let L(t2, body) = if ret.is_some() {
+            // the body cannot be an arbitrary expression once there is a return type
+            assert!(
+                self.peek(|tt| tt.is_delim(Delimiter::Braces)),
+                "a closure with a return type must have a block body"
+            );
+            self.parse_block().map(|block| Expr {
+                attrs: List::default(),
+                kind: ExprKind::Block(LabeledBlock { label: None, block }),
+            })
+        } else {
+            self.parse_expr_inner(allow_struct)
+        };
         Some(
-            t0 << Closure {
+            tnext << Closure {
+                binder,
+                static_,
+                async_,
+                move_,
                 bar1: Token![|],
                 t1,
                 args,
                 bar2: Token![|],
                 ret,
                 t2,
-                body,
+                body: Box::new(body),
             },
         )
     }
@@ -493,22 +601,49 @@ impl<'src> super::Parser<'src> {
         allow_struct: bool,
     ) -> ExprKind {
         if qpath.qself.is_none() && self.check_punct(Punct::Bang) && qpath.path.has_no_args() {
-            let t1 = self.eat_punct(Punct::Bang).unwrap();
-            let L(t2, inner) = self.eat_delimited().unwrap();
-            ExprKind::Macro(MacroCall {
-                path: qpath.path,
-                t1,
-                bang: Token![!],
-                t2,
-                inner,
-            })
+            ExprKind::Macro(Box::new(self.parse_rest_of_macro_call(qpath.path)))
         } else if allow_struct && self.peek(|tt| tt.is_delim(Delimiter::Braces)) {
-            ExprKind::Struct(self.parse_rest_of_struct(qpath))
+            ExprKind::Struct(Box::new(self.parse_rest_of_struct(qpath)))
         } else {
-            ExprKind::QPath(qpath)
+            ExprKind::QPath(Box::new(qpath))
+        }
+    }
+
+    pub(super) fn parse_rest_of_macro_call(&mut self, path: Path) -> MacroCall {
+        let t1 = self.eat_punct(Punct::Bang).unwrap();
+        let L(t2, inner) = self.eat_delimited().unwrap();
+        MacroCall {
+            path,
+            t1,
+            bang: Token![!],
+            t2,
+            inner,
+        }
+    }
+
+    /// Whether the tokens ahead are a path followed by `!` and a delimited group.
+    pub(super) fn check_macro_call(&self) -> bool {
+        let mut n = usize::from(self.check_punct(Punct::ColonColon));
+        loop {
+            if !self.peek_nth(n, |L(_, tt)| matches!(tt, TokenTree::Ident(_))) {
+                return false;
+            }
+            n += 1;
+            if self.peek_nth(n, |L(_, tt)| tt.is_punct(Punct::ColonColon)) {
+                n += 1;
+                continue;
+            }
+            break self.peek_nth(n, |L(_, tt)| tt.is_punct(Punct::Bang))
+                && self.peek_nth(n + 1, |L(_, tt)| matches!(tt, TokenTree::Group(_)));
         }
     }
 
+    /// Continue parsing an expression statement that starts with `atom`.
+    pub(super) fn parse_rest_of_expr(&mut self, atom: ExprKind) -> Expr {
+        let kind = self.parse_trailer_expr(atom);
+        self.parse_expr_finish(Expr { attrs: List::default(), kind }, true, Precedence::MIN)
+    }
+
     fn parse_label(&mut self) -> L<(Ident, Trivia, Token![:])> {
         let lbl = self.bump().map(|x| x.into_lifetime().unwrap());
         let t = self.eat_punct(Punct::Colon).unwrap();
@@ -527,15 +662,13 @@ impl<'src> super::Parser<'src> {
                     .map(ExprKind::Block)
             });
 
-        match &mut e {
-            ExprKind::Block(LabeledBlock { label, block: _ })
-            | ExprKind::Loop(Loop { label, .. })
-            | ExprKind::While(While { label, .. })
-            | ExprKind::For(For { label, .. }) => {
-                *label = Some(Label { lt, t1, colon, t2 });
-            }
+        let label = match &mut e {
+            ExprKind::Block(LabeledBlock { label, block: _ }) | ExprKind::Loop(Loop { label, .. }) => label,
+            ExprKind::While(while_) => &mut while_.label,
+            ExprKind::For(for_) => &mut for_.label,
             _ => unreachable!(),
-        }
+        };
+        *label = Some(Label { lt, t1, colon, t2 });
         t0 << e
     }
 
@@ -570,7 +703,7 @@ impl<'src> super::Parser<'src> {
     }
 
     fn parse_expr_if(&mut self) -> Option<L<ExprKind>> {
-        self.parse_expr_if_inner().map(|x| x.map(ExprKind::If))
+        self.parse_expr_if_inner().map(|x| x.map(|if_| ExprKind::If(Box::new(if_))))
     }
 
     fn parse_expr_loop(&mut self) -> Option<L<ExprKind>> {
@@ -588,12 +721,12 @@ impl<'src> super::Parser<'src> {
 
     fn parse_expr_for(&mut self) -> Option<L<ExprKind>> {
         let t0 = self.eat_kw("for")?;
-        let L(t1, pat) = self.parse_pat();
+        let L(t1, pat) = self.parse_multi_pat_with_leading_vert();
         let t2 = self.eat_kw("in").unwrap();
         let L(t3, expr) = self.parse_expr_inner(false);
         let L(t4, block) = self.parse_block();
         Some(
-            t0 << ExprKind::For(For {
+            t0 << ExprKind::For(Box::new(For {
                 label: None,
                 token: Token![for],
                 t1,
@@ -604,7 +737,7 @@ impl<'src> super::Parser<'src> {
                 expr: Box::new(expr),
                 t4,
                 block,
-            }),
+            })),
         )
     }
 
@@ -613,25 +746,28 @@ impl<'src> super::Parser<'src> {
         let L(t1, cond) = self.parse_expr_inner(false);
         let L(t2, then) = self.parse_block();
         Some(
-            t0 << ExprKind::While(While {
+            t0 << ExprKind::While(Box::new(While {
                 label: None,
                 token: Token![while],
                 t1,
                 cond: Box::new(cond),
                 t2,
                 then,
-            }),
+            })),
         )
     }
 
-    fn parse_expr_range_to(&mut self, allow_struct: bool) -> Option<L<ExprKind>> {
-        let (tprev, limits) = self
-            .eat_punct(Punct::DotDot)
-            .map(|t| (t, RangeLimits::Closed(Token![..])))
+    pub(super) fn parse_range_limits(&mut self) -> Option<L<RangeLimits>> {
+        self.eat_punct(Punct::DotDot)
+            .map(|t| t << RangeLimits::HalfOpen(Token![..]))
             .or_else(|| {
                 self.eat_punct(Punct::DotDotEq)
-                    .map(|t| (t, RangeLimits::HalfOpen(Token![..=])))
-            })?;
+                    .map(|t| t << RangeLimits::Closed(Token![..=]))
+            })
+    }
+
+    fn parse_expr_range_to(&mut self, allow_struct: bool) -> Option<L<ExprKind>> {
+        let L(tprev, limits) = self.parse_range_limits()?;
 
         let end = self.parse_expr_range_end(&limits, allow_struct);
         Some(
@@ -648,19 +784,8 @@ impl<'src> super::Parser<'src> {
             t << ExprKind::Literal(l)
         } else if self.peek(|x| x.is_delim(Delimiter::Parens)) {
             self.parse_paren_or_tuple()
-        } else if self.check_ident("async")
-            && self.peek2(|tt| {
-                tt.is_delim(Delimiter::Braces)
-                    || (tt.is_ident("move") && self.peek3(|tt| tt.is_delim(Delimiter::Braces)))
-            })
-        {
-            let t = self.eat_ident("async").unwrap().0;
-            let L(t1, block) = self.parse_block();
-            t << ExprKind::AsyncBlock(AsyncBlock {
-                token: Token![async],
-                t1,
-                block,
-            })
+        } else if let Some(e) = self.parse_async_block() {
+            e
         } else if let Some(e) = self
             .parse_try_block()
             .or_else(|| self.parse_const_block())
@@ -669,7 +794,7 @@ impl<'src> super::Parser<'src> {
             .or_else(|| self.parse_array_or_repeat())
             .or_else(|| {
                 self.parse_closure(allow_struct)
-                    .map(|x| x.map(ExprKind::Closure))
+                    .map(|x| x.map(|closure| ExprKind::Closure(Box::new(closure))))
             })
             .or_else(|| self.parse_expr_while())
             .or_else(|| self.parse_expr_for())
@@ -726,7 +851,7 @@ impl<'src> super::Parser<'src> {
                 token: Token![return],
                 expr,
             })
-        } else if let Some(t) = self.eat_kw("yield") {
+        } else if self.config.nightly && let Some(t) = self.eat_kw("yield") {
             let expr = if self.peek_expr() {
                 Some(self.parse_expr().map(Box::new))
             } else {
@@ -737,7 +862,37 @@ impl<'src> super::Parser<'src> {
                 token: Token![yield],
                 expr,
             })
-        } else if let Some(t) = self.eat_kw("become") {
+        } else if self.config.nightly && self.check_ident("do") && self.peek2(|tt| tt.is_ident("yeet")) {
+            let t = self.eat_kw("do").unwrap();
+            let t1 = self.eat_kw("yeet").unwrap();
+            let expr = if self.peek_expr() {
+                Some(self.parse_expr().map(Box::new))
+            } else {
+                None
+            };
+            t << ExprKind::Yeet(Yeet {
+                do_: Token![do],
+                t1: TriviaN::new(t1),
+                yeet: Token![yeet],
+                expr,
+            })
+        } else if self.config.nightly && self.check_ident("builtin") && self.peek2(|tt| tt.is_punct(Punct::Pound)) {
+            let t = self.eat_kw("builtin").unwrap();
+            let t1 = self.eat_punct(Punct::Pound).unwrap();
+            let L(t2, name) = self.parse_ident();
+            let Some(L(t3, Delimited::Parens(args))) = self.eat_delimited() else {
+                panic!("expected parenthesized arguments after `builtin # {}`", name.0);
+            };
+            t << ExprKind::Builtin(Builtin {
+                kw: Token![builtin],
+                t1,
+                pound: Token![#],
+                t2,
+                name,
+                t3,
+                args,
+            })
+        } else if self.config.nightly && let Some(t) = self.eat_kw("become") {
             let L(t1, expr) = self.parse_expr().map(Box::new);
             t << ExprKind::Become(Become {
                 token: Token![become],
@@ -745,11 +900,181 @@ impl<'src> super::Parser<'src> {
                 expr,
             })
         } else {
-            let L(t, qpath) = self.parse_qpath();
+            let L(t, qpath) = self.parse_qpath(PathStyle::Expr);
             t << self.parse_rest_of_path_or_macro_or_struct(qpath, allow_struct)
         }
     }
 
+    fn parse_call_args(&mut self) -> L<Parens<CommaSepExprs>> {
+        self.eat_delim(Delimiter::Parens, |t0, mut this| {
+            if let Some(eof) = this.eat_eof() {
+                return t0
+                    << Parens(CommaSepExprs {
+                        t1: eof,
+                        contents: SeparatedList::new(),
+                    });
+            }
+
+            let L(t1, mut list) = this.parse_expr().map(SeparatedList::new_single);
+            loop {
+                if let Some(eof) = this.eat_eof() {
+                    list.push_trivia(eof);
+                    return t0 << Parens(CommaSepExprs { t1, contents: list });
+                }
+                let t = this.eat_punct(Punct::Comma).unwrap();
+                list.push_sep(t, Token![,]);
+                if let Some(eof) = this.eat_eof() {
+                    list.push_trivia(eof);
+                    return t0 << Parens(CommaSepExprs { t1, contents: list });
+                }
+                let L(t, x) = this.parse_expr();
+                list.push_value(t, x);
+            }
+        })
+        .unwrap()
+    }
+
+    // <atom> . <ident> (..<args>) ...
+    // <atom> . <ident> ...
+    // <atom> . <lit> ...
+    // <atom> . await ...
+    // <atom> . use ...
+    // <atom> . match { <arms> } ...
+    fn parse_dot_trailer(&mut self, e: ExprKind) -> ExprKind {
+        let t1 = self.eat_punct(Punct::Dot).unwrap();
+        self.parse_dot_member(e, t1)
+    }
+
+    // everything after the `.` of a trailer, `t1` being the trivia before it
+    fn parse_dot_member(&mut self, e: ExprKind, t1: Trivia) -> ExprKind {
+        if let Some(t2) = self.eat_kw("await") {
+            return ExprKind::Await(ExprAwait {
+                expr: Box::new(e),
+                t1,
+                dot: Token![.],
+                t2,
+                await_: Token![await],
+            });
+        }
+        if self.config.nightly {
+            if let Some(t2) = self.eat_kw("use") {
+                return ExprKind::Use(ExprUse {
+                    expr: Box::new(e),
+                    t1,
+                    dot: Token![.],
+                    t2,
+                    use_: Token![use],
+                });
+            }
+            if let Some(t2) = self.eat_kw("try") {
+                return ExprKind::PostfixTry(ExprPostfixTry {
+                    expr: Box::new(e),
+                    t1,
+                    dot: Token![.],
+                    t2,
+                    try_: Token![try],
+                });
+            }
+            if let Some(t2) = self.eat_kw("match") {
+                let L(t3, arms) = self.parse_arms();
+                return ExprKind::PostfixMatch(ExprPostfixMatch {
+                    expr: Box::new(e),
+                    t1,
+                    dot: Token![.],
+                    t2,
+                    match_: Token![match],
+                    t3,
+                    arms,
+                });
+            }
+        }
+        if let Some(L(t2, lit)) = self.eat_literal() {
+            return match lit.kind {
+                LiteralKind::Int => ExprKind::Field(ExprField {
+                    expr: Box::new(e),
+                    t1,
+                    dot: Token![.],
+                    t2,
+                    member: Member::Unnamed(lit),
+                }),
+                // `a.1.2` is lexed as `a`, `.`, `1.2`
+                LiteralKind::Float => {
+                    let Some((first, second)) = lit.symbol.split_once('.') else {
+                        panic!("unexpected float literal as tuple index: {}", lit.symbol)
+                    };
+                    assert!(
+                        !first.is_empty() && second.bytes().all(|b| b.is_ascii_digit()),
+                        "unexpected float literal as tuple index: {}",
+                        lit.symbol
+                    );
+                    // `a.1. b` is lexed as `a`, `.`, `1.`, `b`, so the member after the `.` is still to come
+                    if second.is_empty() {
+                        assert!(lit.suffix.is_empty(), "unexpected float literal as tuple index: {}", lit.symbol);
+                        let inner = ExprKind::Field(ExprField {
+                            expr: Box::new(e),
+                            t1,
+                            dot: Token![.],
+                            t2,
+                            member: Member::Unnamed(Literal {
+                                kind: LiteralKind::Int,
+                                symbol: first.into(),
+                                suffix: Default::default(),
+                            }),
+                        });
+                        return self.parse_dot_member(inner, Trivia::default());
+                    }
+                    let inner = ExprKind::Field(ExprField {
+                        expr: Box::new(e),
+                        t1,
+                        dot: Token![.],
+                        t2,
+                        member: Member::Unnamed(Literal {
+                            kind: LiteralKind::Int,
+                            symbol: first.into(),
+                            suffix: Default::default(),
+                        }),
+                    });
+                    ExprKind::Field(ExprField {
+                        expr: Box::new(inner),
+                        t1: Trivia::default(),
+                        dot: Token![.],
+                        t2: Trivia::default(),
+                        member: Member::Unnamed(Literal {
+                            kind: LiteralKind::Int,
+                            symbol: second.into(),
+                            suffix: lit.suffix,
+                        }),
+                    })
+                }
+                _ => panic!("unexpected literal after `.`"),
+            };
+        }
+        let L(t2, ident) = self.parse_ident();
+        if self.check_punct(Punct::ColonColon) || self.peek(|tt| tt.is_delim(Delimiter::Parens)) {
+            let args = self
+                .check_punct(Punct::ColonColon)
+                .then(|| self.parse_angle_bracketed_args());
+            let L(t3, args_) = self.parse_call_args();
+            ExprKind::MethodCall(Box::new(ExprMethodCall {
+                receiver: Box::new(e),
+                t1,
+                dot: Token![.],
+                t2,
+                method: PathSegment { ident, args },
+                t3,
+                args: args_,
+            }))
+        } else {
+            ExprKind::Field(ExprField {
+                expr: Box::new(e),
+                t1,
+                dot: Token![.],
+                t2,
+                member: Member::Named(ident),
+            })
+        }
+    }
+
     // <atom> (..<args>) ...
     // <atom> . <ident> (..<args>) ...
     // <atom> . <ident> ...
@@ -759,78 +1084,425 @@ impl<'src> super::Parser<'src> {
     fn parse_trailer_expr(&mut self, mut e: ExprKind) -> ExprKind {
         loop {
             if self.peek(|tt| tt.is_delim(Delimiter::Parens)) {
-                e = self
-                    .eat_delim(Delimiter::Parens, |t0, mut this| {
-                        if let Some(eof) = this.eat_eof() {
-                            return ExprCall {
-                                callee: Box::new(e),
-                                t0,
-                                args: Parens(CommaSepExprs {
-                                    t1: eof,
-                                    contents: SeparatedList::new(),
-                                }),
-                            };
-                        }
-
-                        let L(t1, mut list) = this.parse_expr().map(SeparatedList::new_single);
-                        loop {
-                            if let Some(eof) = this.eat_eof() {
-                                list.push_trivia(eof);
-                                return ExprCall {
-                                    callee: Box::new(e),
-                                    t0,
-                                    args: Parens(CommaSepExprs { t1, contents: list }),
-                                };
-                            }
-                            let t = this.eat_punct(Punct::Comma).unwrap();
-                            list.push_sep(t, Token![,]);
-                            if let Some(eof) = this.eat_eof() {
-                                list.push_trivia(eof);
-                                return ExprCall {
-                                    callee: Box::new(e),
-                                    t0,
-                                    args: Parens(CommaSepExprs { t1, contents: list }),
-                                };
-                            }
-                            let L(t, x) = this.parse_expr();
-                            list.push_value(t, x);
-                        }
-                    })
-                    .map(ExprKind::Call)
-                    .unwrap();
+                let L(t0, args) = self.parse_call_args();
+                e = ExprKind::Call(ExprCall {
+                    callee: Box::new(e),
+                    t0,
+                    args,
+                });
             } else if !matches!(e, ExprKind::Range(_)) && self.check_punct(Punct::Dot) {
-                let t0 = self.eat_punct(Punct::Dot).unwrap();
-
+                e = self.parse_dot_trailer(e);
+            } else if let Some((t1, index)) = self.eat_delim(Delimiter::Brackets, |t1, mut this| {
+                let L(t2, index) = this.parse_expr().map(Box::new);
+                let t3 = this.eat_eof().unwrap();
+                (t1, IndexInner { t2, index, t3 })
+            }) {
+                e = ExprKind::Index(ExprIndex {
+                    expr: Box::new(e),
+                    t1,
+                    index: Brackets(index),
+                });
+            } else if let Some(t1) = self.eat_punct(Punct::Question) {
+                e = ExprKind::Try(ExprTry {
+                    expr: Box::new(e),
+                    t1,
+                    question: Token![?],
+                });
             } else {
                 break e;
             }
         }
     }
+    /// Parses a const generic argument: a block, a literal, possibly negated, or a path.
+    pub(super) fn parse_const_arg(&mut self) -> L<Expr> {
+        self.parse_unary_expr(false)
+    }
     fn parse_unary_expr(&mut self, allow_struct: bool) -> L<Expr> {
         let (t0, mut attrs) = self.parse_attrs(AttrKind::Outer).unwrap_or_default();
-        let L(t1, kind) = self.parse_atom_expr(allow_struct);
+        let L(t1, kind) = self.parse_unary_expr_kind(allow_struct);
         // TODO audit every usage of this. It is not semantically correct but it sure is convenient
         attrs.push_trivia(t1);
         t0 << Expr { attrs, kind }
     }
+    fn parse_borrow_kind(&mut self) -> BorrowKind {
+        if self.check_ident("raw") && self.peek2(|tt| tt.is_ident("const") || tt.is_ident("mut")) {
+            let t1 = self.eat_kw("raw").unwrap();
+            if let Some(t2) = self.eat_kw("const") {
+                BorrowKind::RawConst(t1, Token![raw], t2, Token![const])
+            } else {
+                let t2 = self.eat_kw("mut").unwrap();
+                BorrowKind::RawMut(t1, Token![raw], t2, Token![mut])
+            }
+        } else if let Some(t1) = self.eat_kw("mut") {
+            BorrowKind::Mut(t1, Token![mut])
+        } else {
+            BorrowKind::Ref
+        }
+    }
+    fn eat_un_op(&mut self) -> Option<L<UnOp>> {
+        let op = match self.token.1 {
+            TokenTree::Punct(Punct::Star) => UnOp::Deref(Token![*]),
+            TokenTree::Punct(Punct::Bang) => UnOp::Not(Token![!]),
+            TokenTree::Punct(Punct::Minus) => UnOp::Neg(Token![-]),
+            _ => return None,
+        };
+        Some(self.bump().0 << op)
+    }
     fn parse_unary_expr_kind(&mut self, allow_struct: bool) -> L<ExprKind> {
-        // TODO
-        self.parse_atom_expr(allow_struct)
+        // `&&a` is two borrows
+        if let Some(t0) = self.eat_punct_split(Punct::And) {
+            let kind = self.parse_borrow_kind();
+            let L(t1, expr) = self.parse_unary_expr(allow_struct).map(Box::new);
+            t0 << ExprKind::Ref(ExprRef {
+                and: Token![&],
+                kind,
+                t1,
+                expr,
+            })
+        } else if let Some(L(t0, op)) = self.eat_un_op() {
+            let L(t1, expr) = self.parse_unary_expr(allow_struct).map(Box::new);
+            t0 << ExprKind::Unary(ExprUnary { op, t1, expr })
+        } else {
+            let L(t, e) = self.parse_atom_expr(allow_struct);
+            t << self.parse_trailer_expr(e)
+        }
     }
-    fn parse_expr_finish(
-        &mut self,
-        lhs: ExprKind,
-        allow_struct: bool,
-        base: Precedence,
-    ) -> ExprKind {
-        #![allow(unused_variables)]
-        // TODO
-        lhs
+
+    /// Peek a binary operator without consuming it. Shifts are lexed as two separate
+    /// `<`/`>` tokens, so they are recognized only if nothing is between the two.
+    fn peek_binop(&self) -> Option<BinOp> {
+        let TokenTree::Punct(p) = self.token.1 else {
+            return None;
+        };
+        Some(match p {
+            Punct::Plus => BinOp::Add(Token![+]),
+            Punct::Minus => BinOp::Sub(Token![-]),
+            Punct::Star => BinOp::Mul(Token![*]),
+            Punct::Slash => BinOp::Div(Token![/]),
+            Punct::Percent => BinOp::Rem(Token![%]),
+            Punct::AndAnd => BinOp::And(Token![&&]),
+            Punct::OrOr => BinOp::Or(Token![||]),
+            Punct::Caret => BinOp::BitXor(Token![^]),
+            Punct::And => BinOp::BitAnd(Token![&]),
+            Punct::Or => BinOp::BitOr(Token![|]),
+            Punct::Lt if self.peek2_joint_punct(Punct::Lt) => BinOp::Shl(Token![<<]),
+            Punct::Gt if self.peek2_joint_punct(Punct::Gt) => BinOp::Shr(Token![>>]),
+            Punct::EqEq => BinOp::Eq(Token![==]),
+            // `a<-b` is lexed with a `<-`
+            Punct::Lt | Punct::LThinArrow => BinOp::Lt(Token![<]),
+            Punct::LtEq => BinOp::Le(Token![<=]),
+            Punct::BangEq => BinOp::Ne(Token![!=]),
+            Punct::GtEq => BinOp::Ge(Token![>=]),
+            Punct::Gt => BinOp::Gt(Token![>]),
+            _ => return None,
+        })
+    }
+
+    /// Eat the tokens of an operator returned by [`Self::peek_binop`].
+    fn eat_binop(&mut self, op: &BinOp) -> Trivia {
+        match op {
+            BinOp::Shl(_) => {
+                let t = self.bump().0;
+                self.eat_punct_split(Punct::Lt).unwrap();
+                t
+            }
+            BinOp::Shr(_) => {
+                let t = self.bump().0;
+                self.eat_punct_split(Punct::Gt).unwrap();
+                t
+            }
+            BinOp::Lt(_) => self.eat_punct_split(Punct::Lt).unwrap(),
+            _ => self.bump().0,
+        }
+    }
+
+    fn eat_assign_op(&mut self) -> Option<L<AssignOp>> {
+        let TokenTree::Punct(p) = self.token.1 else {
+            return None;
+        };
+        let op = match p {
+            Punct::PlusEq => AssignOp::AddAssign(Token![+=]),
+            Punct::MinusEq => AssignOp::SubAssign(Token![-=]),
+            Punct::StarEq => AssignOp::MulAssign(Token![*=]),
+            Punct::SlashEq => AssignOp::DivAssign(Token![/=]),
+            Punct::PercentEq => AssignOp::RemAssign(Token![%=]),
+            Punct::CaretEq => AssignOp::BitXorAssign(Token![^=]),
+            Punct::AndEq => AssignOp::BitAndAssign(Token![&=]),
+            Punct::OrEq => AssignOp::BitOrAssign(Token![|=]),
+            Punct::LtLtEq => AssignOp::ShlAssign(Token![<<=]),
+            Punct::GtGtEq => AssignOp::ShrAssign(Token![>>=]),
+            _ => return None,
+        };
+        Some(self.bump().0 << op)
+    }
+
+    fn check_assign_op(&self) -> bool {
+        matches!(
+            self.token.1,
+            TokenTree::Punct(
+                Punct::PlusEq
+                    | Punct::MinusEq
+                    | Punct::StarEq
+                    | Punct::SlashEq
+                    | Punct::PercentEq
+                    | Punct::CaretEq
+                    | Punct::AndEq
+                    | Punct::OrEq
+                    | Punct::LtLtEq
+                    | Punct::GtGtEq
+            )
+        )
+    }
+
+    fn peek_precedence(&self) -> Precedence {
+        if let Some(op) = self.peek_binop() {
+            Precedence::of_binop(&op)
+        } else if self.check_assign_op() || self.check_punct(Punct::Eq) {
+            Precedence::Assign
+        } else if self.check_punct(Punct::DotDot) || self.check_punct(Punct::DotDotEq) {
+            Precedence::Range
+        } else if self.check_ident("as") {
+            Precedence::Cast
+        } else {
+            Precedence::MIN
+        }
+    }
+
+    fn parse_expr_finish(&mut self, mut lhs: Expr, allow_struct: bool, base: Precedence) -> Expr {
+        loop {
+            if matches!(lhs.kind, ExprKind::Range(_)) {
+                // A range cannot be the left-hand side of another binary operator.
+                break lhs;
+            } else if let Some(op) = self.peek_binop() {
+                let precedence = Precedence::of_binop(&op);
+                if precedence < base {
+                    break lhs;
+                }
+                if precedence == Precedence::Compare
+                    && matches!(&lhs.kind, ExprKind::Binary(b) if Precedence::of_binop(&b.op) == Precedence::Compare)
+                {
+                    panic!("comparison operators cannot be chained");
+                }
+                let t1 = self.eat_binop(&op);
+                let L(t2, rhs) = self.parse_binop_rhs(allow_struct, precedence);
+                let kind = ExprKind::Binary(ExprBinary {
+                    lhs: Box::new(lhs),
+                    t1,
+                    op,
+                    t2,
+                    rhs,
+                });
+                lhs = Expr { attrs: List::default(), kind };
+            } else if Precedence::Assign >= base && self.check_assign_op() {
+                let L(t1, op) = self.eat_assign_op().unwrap();
+                let L(t2, rhs) = self.parse_binop_rhs(allow_struct, Precedence::Assign);
+                let kind = ExprKind::AssignOp(ExprAssignOp {
+                    lhs: Box::new(lhs),
+                    t1,
+                    op,
+                    t2,
+                    rhs,
+                });
+                lhs = Expr { attrs: List::default(), kind };
+            } else if Precedence::Assign >= base && self.check_punct(Punct::Eq) {
+                let t1 = self.eat_punct(Punct::Eq).unwrap();
+                let L(t2, rhs) = self.parse_binop_rhs(allow_struct, Precedence::Assign);
+                let kind = ExprKind::Assign(ExprAssign {
+                    lhs: Box::new(lhs),
+                    t1,
+                    eq: Token![=],
+                    t2,
+                    rhs,
+                });
+                lhs = Expr { attrs: List::default(), kind };
+            } else if Precedence::Range >= base
+                && let Some(L(t1, limits)) = self.parse_range_limits()
+            {
+                let end = self.parse_expr_range_end(&limits, allow_struct);
+                let kind = ExprKind::Range(ExprRange {
+                    start: Some((Box::new(lhs), t1)),
+                    limits,
+                    end,
+                });
+                lhs = Expr { attrs: List::default(), kind };
+            } else if Precedence::Cast >= base && let Some(t1) = self.eat_kw("as") {
+                // rustc reads `a as usize < b` as the start of `usize<b, ..>`
+                assert!(
+                    !self.check_unclosed_cast_generics(),
+                    "`<` after a cast is interpreted as the start of generic arguments, not a comparison"
+                );
+                let L(t2, ty) = self.parse_ty().map(Box::new);
+                self.check_cast();
+                let kind = ExprKind::Cast(ExprCast {
+                    expr: Box::new(lhs),
+                    t1,
+                    as_: Token![as],
+                    t2,
+                    ty,
+                });
+                lhs = Expr { attrs: List::default(), kind };
+            } else {
+                break lhs;
+            }
+        }
+    }
+
+    /// Whether the type after `as` is a path followed by a `<` that is never closed, as in
+    /// `a as usize < b` or `a as usize << b`, so that it cannot be parsed as generic args.
+    fn check_unclosed_cast_generics(&self) -> bool {
+        let mut this = self.snapshot();
+        if !this.peek(|tt| matches!(tt, TokenTree::Ident(_))) {
+            return false;
+        }
+        this.bump();
+        while this.check_punct(Punct::ColonColon) && this.peek2(|tt| matches!(tt, TokenTree::Ident(_))) {
+            this.bump();
+            this.bump();
+        }
+        if !this.check_punct_split(Punct::Lt) {
+            return false;
+        }
+        // count angle brackets like `check_assoc_constraint`, stopping at tokens that cannot
+        // appear in generic args
+        let mut depth = 0isize;
+        loop {
+            let L(_, tt) = this.bump();
+            match tt {
+                TokenTree::Punct(Punct::Lt | Punct::LtEq | Punct::LThinArrow) => depth += 1,
+                TokenTree::Punct(Punct::Gt | Punct::GtEq) => depth -= 1,
+                TokenTree::Punct(Punct::GtGtEq) => depth -= 2,
+                TokenTree::Punct(
+                    Punct::Semi
+                    | Punct::Dot
+                    | Punct::EqEq
+                    | Punct::BangEq
+                    | Punct::OrOr
+                    | Punct::Slash
+                    | Punct::Percent
+                    | Punct::Caret
+                    | Punct::RFatArrow,
+                )
+                | TokenTree::Eof => return true,
+                _ => {}
+            }
+            if depth <= 0 {
+                return false;
+            }
+        }
+    }
+
+    fn check_cast(&self) {
+        let kind = if self.check_punct(Punct::Dot) {
+            if self.peek2(|tt| tt.is_ident("await")) {
+                "`.await`"
+            } else if self.peek3(|tt| tt.is_delim(Delimiter::Parens) || tt.is_punct(Punct::ColonColon)) {
+                "a method call"
+            } else {
+                "a field access"
+            }
+        } else if self.check_punct(Punct::Question) {
+            "`?`"
+        } else if self.peek(|tt| tt.is_delim(Delimiter::Brackets)) {
+            "indexing"
+        } else if self.peek(|tt| tt.is_delim(Delimiter::Parens)) {
+            "a function call"
+        } else {
+            return;
+        };
+        panic!("casts cannot be followed by {kind}");
     }
 
     fn parse_binop_rhs(&mut self, allow_struct: bool, precedence: Precedence) -> L<Box<Expr>> {
-        #![allow(unused_variables)]
-        // TODO
-        self.parse_unary_expr(allow_struct).map(Box::new)
+        let L(t0, mut rhs) = self.parse_unary_expr(allow_struct);
+        loop {
+            let next = self.peek_precedence();
+            // a range cannot be extended further, so stop here instead of looping forever
+            if matches!(rhs.kind, ExprKind::Range(_)) {
+                break;
+            }
+            if next > precedence || next == precedence && precedence == Precedence::Assign {
+                rhs = self.parse_expr_finish(rhs, allow_struct, next);
+            } else {
+                break;
+            }
+        }
+        t0 << Box::new(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn parse_expr(s: &str) -> Expr {
+        Parser::new(s).parse_expr().1
+    }
+
+    // operands can carry the whitespace that preceded them
+    fn print(e: &Expr) -> String {
+        let mut out = String::new();
+        e.print(&mut out);
+        out.trim().to_owned()
+    }
+
+    #[test]
+    #[should_panic = "`<` after a cast is interpreted as the start of generic arguments"]
+    fn test_cast_then_lt() {
+        parse_expr("x as usize < y");
+    }
+
+    #[test]
+    #[should_panic = "`<` after a cast is interpreted as the start of generic arguments"]
+    fn test_cast_then_shl() {
+        parse_expr("x as usize << y");
+    }
+
+    #[test]
+    fn test_cast_to_generic_ty() {
+        parse_expr("x as Vec<u8> < y");
+        parse_expr("x as a::B<C<u8>> >= y");
+    }
+
+    #[test]
+    fn test_mul_binds_tighter_than_add() {
+        let ExprKind::Binary(ExprBinary { lhs, op: BinOp::Add(_), rhs, .. }) = parse_expr("a + b * c").kind else {
+            panic!("expected an addition");
+        };
+        assert_eq!(print(&lhs), "a");
+        assert!(matches!(rhs.kind, ExprKind::Binary(ExprBinary { op: BinOp::Mul(_), .. })));
+        assert_eq!(print(&rhs), "b * c");
+    }
+
+    #[test]
+    fn test_sub_is_left_associative() {
+        let ExprKind::Binary(ExprBinary { lhs, op: BinOp::Sub(_), rhs, .. }) = parse_expr("a - b - c").kind else {
+            panic!("expected a subtraction");
+        };
+        assert!(matches!(lhs.kind, ExprKind::Binary(ExprBinary { op: BinOp::Sub(_), .. })));
+        assert_eq!(print(&lhs), "a - b");
+        assert_eq!(print(&rhs), "c");
+    }
+
+    #[test]
+    fn test_assign_is_right_associative() {
+        let ExprKind::Assign(ExprAssign { lhs, rhs, .. }) = parse_expr("a = b = c").kind else {
+            panic!("expected an assignment");
+        };
+        assert_eq!(print(&lhs), "a");
+        assert!(matches!(rhs.kind, ExprKind::Assign(_)));
+        assert_eq!(print(&rhs), "b = c");
+    }
+
+    #[test]
+    fn test_range_does_not_chain() {
+        let mut parser = Parser::new("a..b..c");
+        let L(_, e) = parser.parse_expr();
+        let ExprKind::Range(ExprRange { start: Some((start, _)), end: Some(L(_, end)), .. }) = e.kind else {
+            panic!("expected a range");
+        };
+        assert_eq!(print(&start), "a");
+        assert_eq!(print(&end), "b");
+        // the second `..` is left for the caller to reject
+        assert!(parser.check_punct(Punct::DotDot));
     }
 }
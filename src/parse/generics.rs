@@ -0,0 +1,240 @@
+use crate::prelude::*;
+use crate::parse::attr::AttrKind;
+
+impl<'src> super::Parser<'src> {
+    pub fn eat_lifetime(&mut self) -> Option<L<Ident>> {
+        self.eat(|tt| matches!(tt, TokenTree::Lifetime(_)))
+            .map(|tt| tt.map(|tt| tt.into_lifetime().unwrap()))
+    }
+
+    fn parse_lifetime_bounds(&mut self) -> Option<LifetimeBounds> {
+        let t1 = self.eat_punct(Punct::Colon)?;
+        let mut builder = SeparatedListBuilder::new();
+        while let Some(L(t, lifetime)) = self.eat_lifetime() {
+            builder.push_value(t, lifetime);
+            let Some(t) = self.eat_punct(Punct::Plus) else { break };
+            builder.push_sep(t, Token![+]);
+        }
+        let L(t2, bounds) = builder.build();
+        Some(LifetimeBounds { t1, colon: Token![:], t2, bounds })
+    }
+
+    fn check_type_param_bound(&self) -> bool {
+        self.peek(|tt| {
+            matches!(tt, TokenTree::Lifetime(_) | TokenTree::Ident(_))
+                || tt.is_punct(Punct::ColonColon)
+                || tt.is_punct(Punct::Question)
+                || tt.is_delim(Delimiter::Parens)
+        }) || self.config.nightly && self.peek(|tt| tt.is_punct(Punct::Bang) || tt.is_punct(Punct::Tilde))
+    }
+
+    fn parse_trait_bound(&mut self) -> L<TraitBound> {
+        let bound_lifetimes = self.parse_bound_lifetimes();
+        // everything but `?` is nightly only
+        let nightly = self.config.nightly;
+        let constness = if !nightly {
+            None
+        } else if let Some(t) = self.eat_kw("const") {
+            Some((t, BoundConstness::Const(Token![const])))
+        } else {
+            self.eat_punct(Punct::Tilde).map(|t| {
+                let t1 = self.eat_kw("const").expect("expected `const` after `~`");
+                (t, BoundConstness::Maybe(Token![~], t1, Token![const]))
+            })
+        };
+        let tasync = if nightly { self.eat_kw("async") } else { None };
+        let polarity = if let Some(t) = self.eat_punct(Punct::Question) {
+            Some((t, BoundPolarity::Maybe(Token![?])))
+        } else if nightly {
+            self.eat_punct(Punct::Bang).map(|t| (t, BoundPolarity::Negative(Token![!])))
+        } else {
+            None
+        };
+        let L(mut tnext, path) = self.parse_path(PathStyle::Type);
+        // the trivia before each modifier trails the modifier preceding it
+        let polarity = polarity.map(|(t, p)| (p, std::mem::replace(&mut tnext, t)));
+        let async_ = tasync.map(|t| (Token![async], std::mem::replace(&mut tnext, t)));
+        let constness = constness.map(|(t, c)| (c, std::mem::replace(&mut tnext, t)));
+        let bound_lifetimes =
+            bound_lifetimes.map(|L(t, bl)| (bl, std::mem::replace(&mut tnext, t)));
+        tnext << TraitBound { bound_lifetimes, constness, async_, polarity, path }
+    }
+
+    /// Parses `use<'a, T>` if the next tokens are `use<`.
+    fn parse_precise_capturing(&mut self) -> Option<L<PreciseCapturing>> {
+        if !(self.check_ident("use") && self.peek2(|tt| tt.is_punct(Punct::Lt))) {
+            return None;
+        }
+        let t0 = self.eat_kw("use").unwrap();
+        let t1 = self.eat_punct(Punct::Lt).unwrap();
+        let mut builder = SeparatedListBuilder::new();
+        let tlast = loop {
+            if let Some(tlast) = self.eat_punct_split(Punct::Gt) {
+                break tlast;
+            }
+            let L(t, arg) = match self.eat_lifetime() {
+                Some(lifetime) => lifetime.map(PreciseCapturingArg::Lifetime),
+                None => self.parse_ident().map(PreciseCapturingArg::Param),
+            };
+            builder.push_value(t, arg);
+            if let Some(tlast) = self.eat_punct_split(Punct::Gt) {
+                break tlast;
+            }
+            let t = self.eat_punct(Punct::Comma).unwrap();
+            builder.push_sep(t, Token![,]);
+        };
+        let L(t2, mut args) = builder.build();
+        args.push_trivia(tlast);
+        Some(t0 << PreciseCapturing { kw: Token![use], t1, lt: Token![<], t2, args, gt: Token![>] })
+    }
+
+    fn parse_type_param_bound(&mut self) -> L<TypeParamBound> {
+        if let Some(lifetime) = self.eat_lifetime() {
+            lifetime.map(TypeParamBound::Lifetime)
+        } else if let Some(capturing) = self.parse_precise_capturing() {
+            capturing.map(TypeParamBound::Use)
+        } else if let Some(bound) = self.eat_delim(Delimiter::Parens, |t0, mut this| {
+            let L(t1, bound) = this.parse_trait_bound();
+            let t2 = this.eat_eof().unwrap();
+            t0 << TypeParamBound::Paren(Parens(ParenBound { t1, bound, t2 }))
+        }) {
+            bound
+        } else {
+            self.parse_trait_bound().map(TypeParamBound::Trait)
+        }
+    }
+
+    /// Parses `Trait + 'a + Trait`, possibly empty.
+    pub(super) fn parse_type_param_bounds(&mut self) -> L<SeparatedList<TypeParamBound, Token![+]>> {
+        let mut builder = SeparatedListBuilder::new();
+        while self.check_type_param_bound() {
+            let L(t, bound) = self.parse_type_param_bound();
+            builder.push_value(t, bound);
+            let Some(t) = self.eat_punct(Punct::Plus) else { break };
+            builder.push_sep(t, Token![+]);
+        }
+        builder.build()
+    }
+
+    pub(super) fn parse_type_bounds(&mut self) -> Option<TypeBounds> {
+        let t1 = self.eat_punct(Punct::Colon)?;
+        let L(t2, bounds) = self.parse_type_param_bounds();
+        Some(TypeBounds { t1, colon: Token![:], t2, bounds })
+    }
+
+    fn parse_generic_param_kind(&mut self) -> L<GenericParamKind> {
+        if let Some(L(t0, lifetime)) = self.eat_lifetime() {
+            let bounds = self.parse_lifetime_bounds();
+            t0 << GenericParamKind::Lifetime(LifetimeParam { lifetime, bounds })
+        } else if let Some(t0) = self.eat_kw("const") {
+            let L(t1, name) = self.parse_ident();
+            let t2 = self.eat_punct(Punct::Colon).unwrap();
+            let L(t3, ty) = self.parse_ty();
+            let default = self.eat_punct(Punct::Eq).map(|t1| {
+                let L(t2, expr) = self.parse_const_arg();
+                ConstParamDefault { t1, eq: Token![=], t2, expr }
+            });
+            t0 << GenericParamKind::Const(Box::new(ConstParam {
+                kw: Token![const],
+                t1: TriviaN::new(t1),
+                name,
+                t2,
+                colon: Token![:],
+                t3,
+                ty,
+                default,
+            }))
+        } else {
+            let L(t0, name) = self.parse_ident();
+            let bounds = self.parse_type_bounds();
+            let default = self.eat_punct(Punct::Eq).map(|t1| {
+                let L(t2, ty) = self.parse_ty();
+                TypeParamDefault { t1, eq: Token![=], t2, ty }
+            });
+            t0 << GenericParamKind::Type(Box::new(TypeParam { name, bounds, default }))
+        }
+    }
+
+    fn parse_generic_param(&mut self) -> L<GenericParam> {
+        let attrs = self.parse_attrs(AttrKind::Outer);
+        let L(t1, kind) = self.parse_generic_param_kind();
+        let (t0, attrs) = match attrs {
+            Some((t0, mut attrs)) => {
+                attrs.push_trivia(t1);
+                (t0, attrs)
+            }
+            None => (t1, List::default()),
+        };
+        t0 << GenericParam { attrs, kind }
+    }
+
+    /// Parses `<'a, T, const N: usize>` if the next token is `<`.
+    pub fn parse_generics(&mut self) -> Option<L<Generics>> {
+        let t0 = self.eat_punct(Punct::Lt)?;
+        let mut builder = SeparatedListBuilder::new();
+        let tlast = loop {
+            if let Some(tlast) = self.eat_punct_split(Punct::Gt) {
+                break tlast;
+            }
+            let L(t, param) = self.parse_generic_param();
+            builder.push_value(t, param);
+            if let Some(tlast) = self.eat_punct_split(Punct::Gt) {
+                break tlast;
+            }
+            let t = self.eat_punct(Punct::Comma).unwrap();
+            builder.push_sep(t, Token![,]);
+        };
+        let L(t1, mut params) = builder.build();
+        params.push_trivia(tlast);
+        Some(t0 << Generics { lt: Token![<], t1, params, gt: Token![>] })
+    }
+
+    pub(super) fn parse_bound_lifetimes(&mut self) -> Option<L<BoundLifetimes>> {
+        if !(self.check_ident("for") && self.peek2(|tt| tt.is_punct(Punct::Lt))) {
+            return None;
+        }
+        let t0 = self.eat_kw("for").unwrap();
+        let L(t1, generics) = self.parse_generics().unwrap();
+        Some(t0 << BoundLifetimes { for_: Token![for], t1, generics })
+    }
+
+    fn parse_where_predicate(&mut self) -> L<WherePredicate> {
+        if let Some(L(t0, lifetime)) = self.eat_lifetime() {
+            let bounds = self.parse_lifetime_bounds().unwrap();
+            t0 << WherePredicate::Lifetime(WhereLifetimePredicate { lifetime, bounds })
+        } else {
+            let (t0, bound_lifetimes, ty) = if let Some(L(t0, bl)) = self.parse_bound_lifetimes() {
+                let L(t1, ty) = self.parse_ty();
+                (t0, Some((bl, t1)), ty)
+            } else {
+                let L(t0, ty) = self.parse_ty();
+                (t0, None, ty)
+            };
+            let bounds = self.parse_type_bounds().unwrap();
+            t0 << WherePredicate::Type(Box::new(WhereTypePredicate { bound_lifetimes, ty, bounds }))
+        }
+    }
+
+    /// A where clause ends at the item body, a `;` or the `=` of a type alias.
+    fn check_where_clause_end(&self) -> bool {
+        self.peek(|tt| {
+            tt.is_delim(Delimiter::Braces)
+                || tt.is_punct(Punct::Semi)
+                || tt.is_punct(Punct::Eq)
+                || matches!(tt, TokenTree::Eof)
+        })
+    }
+
+    pub fn parse_where_clause(&mut self) -> Option<L<WhereClause>> {
+        let t0 = self.eat_kw("where")?;
+        let mut builder = SeparatedListBuilder::new();
+        while !self.check_where_clause_end() {
+            let L(t, predicate) = self.parse_where_predicate();
+            builder.push_value(t, predicate);
+            let Some(t) = self.eat_punct(Punct::Comma) else { break };
+            builder.push_sep(t, Token![,]);
+        }
+        let L(t1, predicates) = builder.build();
+        Some(t0 << WhereClause { kw: Token![where], t1, predicates })
+    }
+}
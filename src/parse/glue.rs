@@ -1,7 +1,7 @@
 //! Glues lexed tokens to make them more parsable.
 
 use ra_ap_rustc_lexer::TokenKind;
-use smol_str::SmolStr;
+use smol_str::{format_smolstr, SmolStr};
 
 use crate::Lexer;
 use crate::prelude::*;
@@ -79,7 +79,20 @@ impl<'src> Gluer<'src> {
                 let kind = match kind {
                     K::Int { .. } => LiteralKind::Int,
                     K::Float { .. } => LiteralKind::Float,
-                    _ => LiteralKind::Other,
+                    K::Char { .. } => LiteralKind::Char,
+                    K::Byte { .. } => LiteralKind::Byte,
+                    K::Str { .. } => LiteralKind::Str,
+                    K::ByteStr { .. } => LiteralKind::ByteStr,
+                    K::CStr { .. } => LiteralKind::CStr,
+                    K::RawStr { n_hashes } => LiteralKind::RawStr {
+                        hashes: n_hashes.expect("invalid raw string literal"),
+                    },
+                    K::RawByteStr { n_hashes } => LiteralKind::RawByteStr {
+                        hashes: n_hashes.expect("invalid raw string literal"),
+                    },
+                    K::RawCStr { n_hashes } => LiteralKind::RawCStr {
+                        hashes: n_hashes.expect("invalid raw string literal"),
+                    },
                 };
                 let suffix_start = suffix_start as usize;
                 let symbol = SmolStr::new(&s[..suffix_start]);
@@ -87,7 +100,10 @@ impl<'src> Gluer<'src> {
                 TokenTree::Literal(Literal { kind, symbol, suffix })
             }
             TokenKind::Pound => TokenTree::Punct(Punct::Pound),
-            TokenKind::Bang if matches!(self.peek(), (t, TokenKind::Eq, _) if t.is_empty()) => TokenTree::Punct(Punct::BangEq),
+            TokenKind::Bang if matches!(self.peek(), (t, TokenKind::Eq, _) if t.is_empty()) => {
+                self.lexer.next();
+                TokenTree::Punct(Punct::BangEq)
+            }
             TokenKind::Bang => TokenTree::Punct(Punct::Bang),
             TokenKind::Semi => TokenTree::Punct(Punct::Semi),
             TokenKind::Colon if matches!(self.peek(), (t, TokenKind::Colon, _) if t.is_empty()) => {
@@ -120,6 +136,10 @@ impl<'src> Gluer<'src> {
                 self.lexer.next();
                 TokenTree::Punct(Punct::AndEq)
             }
+            TokenKind::And if matches!(self.peek(), (t, TokenKind::And, _) if t.is_empty()) => {
+                self.lexer.next();
+                TokenTree::Punct(Punct::AndAnd)
+            }
             TokenKind::And => TokenTree::Punct(Punct::And),
             TokenKind::Or if matches!(self.peek(), (t, TokenKind::Eq, _) if t.is_empty()) => {
                 self.lexer.next();
@@ -163,12 +183,20 @@ impl<'src> Gluer<'src> {
             | TokenKind::Whitespace => {
                 unreachable!("should be already handled in the lexer")
             }
-            TokenKind::InvalidIdent
-            | TokenKind::Unknown
-            | TokenKind::UnknownPrefix
-            | TokenKind::UnknownPrefixLifetime
-            | TokenKind::GuardedStrPrefix
-            | TokenKind::Frontmatter { .. } => {
+            TokenKind::UnknownPrefix
+                if s == "k"
+                    && matches!(self.peek(), (t, TokenKind::Pound, _) if t.is_empty())
+                    && matches!(self.peek_nth(1), (t, TokenKind::Ident, _) if t.is_empty()) =>
+            {
+                self.lexer.next();
+                let (_, _, ident) = self.lexer.next();
+                TokenTree::RawKeyword(Ident(format_smolstr!("k#{ident}")))
+            }
+            TokenKind::UnknownPrefix => TokenTree::ReservedPrefix(Ident(s)),
+            TokenKind::UnknownPrefixLifetime => TokenTree::ReservedLifetimePrefix(Ident(s)),
+            // the lexer has already eaten the whole guarded string
+            TokenKind::GuardedStrPrefix => TokenTree::GuardedStr(GuardedStr(s)),
+            TokenKind::InvalidIdent | TokenKind::Unknown | TokenKind::Frontmatter { .. } => {
                 panic!("invalid tokens or weird tokens are unsupported")
             }
             TokenKind::Dot if matches!(self.peek(), (t, TokenKind::Dot, _) if t.is_empty()) && matches!(self.peek_nth(1), (t, TokenKind::Dot, _) if t.is_empty()) => {
@@ -217,3 +245,57 @@ impl<'src> Gluer<'src> {
         t0 << tt
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ra_ap_rustc_lexer::FrontmatterAllowed;
+
+    use super::Gluer;
+    use crate::prelude::*;
+
+    fn literal(s: &str) -> Literal {
+        let L(_, tt) = Gluer::new(crate::lex::tokenize(s, FrontmatterAllowed::No)).next();
+        let TokenTree::Literal(lit) = tt else {
+            panic!("expected a literal, found {tt:?}");
+        };
+        lit
+    }
+
+    #[test]
+    fn test_literal_kinds() {
+        #[rustfmt::skip]
+        let cases = [
+            ("12", LiteralKind::Int),
+            ("1e3", LiteralKind::Float),
+            ("'a'", LiteralKind::Char),
+            ("b'a'", LiteralKind::Byte),
+            ("\"a\"", LiteralKind::Str),
+            ("b\"a\"", LiteralKind::ByteStr),
+            ("c\"a\"", LiteralKind::CStr),
+            ("r\"a\"", LiteralKind::RawStr { hashes: 0 }),
+            ("r#\"a\"#", LiteralKind::RawStr { hashes: 1 }),
+            ("r##\"a\"#\"##", LiteralKind::RawStr { hashes: 2 }),
+            ("br\"a\"", LiteralKind::RawByteStr { hashes: 0 }),
+            ("br#\"a\"#", LiteralKind::RawByteStr { hashes: 1 }),
+            ("cr\"a\"", LiteralKind::RawCStr { hashes: 0 }),
+            ("cr##\"a\"##", LiteralKind::RawCStr { hashes: 2 }),
+        ];
+        for (s, kind) in cases {
+            let lit = literal(s);
+            assert_eq!(lit.kind, kind, "{s}");
+            assert_eq!(lit.symbol, s);
+            assert_eq!(lit.suffix, "");
+        }
+    }
+
+    #[test]
+    fn test_literal_suffix() {
+        let lit = literal("1f32");
+        assert_eq!(lit.kind, LiteralKind::Int);
+        assert_eq!((lit.symbol.as_str(), lit.suffix.as_str()), ("1", "f32"));
+
+        let lit = literal("r#\"a\"#_x");
+        assert_eq!(lit.kind, LiteralKind::RawStr { hashes: 1 });
+        assert_eq!((lit.symbol.as_str(), lit.suffix.as_str()), ("r#\"a\"#", "_x"));
+    }
+}
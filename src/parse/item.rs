@@ -11,13 +11,107 @@ fn juggle_trivia(
             attrs.push_trivia(tsquash);
             (t0, attrs, Some((vis, tbeforekw)))
         }
-        (Some((t0, attrs)), None) => (t0, attrs, None),
+        (Some((t0, mut attrs)), None) => {
+            attrs.push_trivia(tbeforekw);
+            (t0, attrs, None)
+        }
         (None, Some(L(t0, vis))) => (t0, List::default(), Some((vis, tbeforekw))),
         (None, None) => (tbeforekw, List::default(), None),
     }
 }
 
 impl Parser<'_> {
+    /// `&self`, `&'a mut self` and friends, but not `&self::CONST`
+    fn check_ref_self_param(&self) -> bool {
+        if !self.check_punct(Punct::And) {
+            return false;
+        }
+        let mut n = 1;
+        if self.peek_nth(n, |L(_, tt)| matches!(tt, TokenTree::Lifetime(_))) {
+            n += 1;
+        }
+        if self.peek_nth(n, |L(_, tt)| tt.is_ident("mut")) {
+            n += 1;
+        }
+        self.peek_nth(n, |L(_, tt)| tt.is_ident("self"))
+            && !self.peek_nth(n + 1, |L(_, tt)| tt.is_punct(Punct::ColonColon))
+    }
+    fn parse_self_param(&mut self) -> Option<L<SelfParam>> {
+        if self.check_ref_self_param() {
+            let t0 = self.eat_punct(Punct::And).unwrap();
+            let lifetime = self.eat_lifetime().map(|L(t, lifetime)| (t, lifetime));
+            let mut_ = self.eat_kw("mut").map(|t| (t, Token![mut]));
+            let t1 = self.eat_kw("self").unwrap();
+            return Some(t0 << SelfParam::Ref { and: Token![&], lifetime, mut_, t1, self_: Token![self] });
+        }
+        let is_self = (self.check_ident("self") && !self.peek2(|tt| tt.is_punct(Punct::ColonColon)))
+            || (self.check_ident("mut") && self.peek2(|tt| tt.is_ident("self")));
+        if !is_self {
+            return None;
+        }
+        let tmut = self.eat_kw("mut");
+        let tself = self.eat_kw("self").unwrap();
+        let (t0, mut_) = match tmut {
+            Some(t0) => (t0, Some((Token![mut], tself))),
+            None => (tself, None),
+        };
+        let param = if let Some(t1) = self.eat_punct(Punct::Colon) {
+            let L(t2, ty) = self.parse_ty();
+            SelfParam::Typed { mut_, self_: Token![self], t1, colon: Token![:], t2, ty }
+        } else {
+            SelfParam::Value { mut_, self_: Token![self] }
+        };
+        Some(t0 << param)
+    }
+    fn parse_fn_param_kind(&mut self) -> L<FnParamKind> {
+        if let Some(t0) = self.eat_punct(Punct::DotDotDot) {
+            return t0 << FnParamKind::Variadic { pat: None, dots: Token![...] };
+        }
+        if let Some(param) = self.parse_self_param() {
+            return param.map(FnParamKind::SelfParam);
+        }
+        let L(t0, pat) = self.parse_pat();
+        let t1 = self.eat_punct(Punct::Colon).unwrap();
+        if let Some(t2) = self.eat_punct(Punct::DotDotDot) {
+            let pat = Some((pat, t1, Token![:], t2));
+            return t0 << FnParamKind::Variadic { pat, dots: Token![...] };
+        }
+        let L(t2, ty) = self.parse_ty();
+        t0 << FnParamKind::Typed { pat, t1, colon: Token![:], t2, ty }
+    }
+    fn check_fn(&self) -> bool {
+        let mut n = 0;
+        for kw in ["const", "async", "unsafe", "safe", "extern"] {
+            if self.peek_nth(n, |L(_, tt)| tt.is_ident(kw)) {
+                n += 1;
+                if kw == "extern" && self.peek_nth(n, |L(_, tt)| matches!(tt, TokenTree::Literal(_))) {
+                    n += 1;
+                }
+            }
+        }
+        self.peek_nth(n, |L(_, tt)| tt.is_ident("fn"))
+    }
+    /// Parses the qualifiers and the `fn` keyword, returning the trivia before the first of them.
+    fn parse_fn_qualifiers(&mut self) -> (Trivia, FnQualifiers) {
+        let tconst = self.eat_kw("const");
+        let tasync = self.eat_kw("async");
+        let safety = if let Some(t) = self.eat_kw("unsafe") {
+            Some((t, Safety::Unsafe(Token![unsafe])))
+        } else {
+            self.eat_kw("safe").map(|t| (t, Safety::Safe(Token![safe])))
+        };
+        let extern_ = self.eat_kw("extern").map(|t| {
+            let abi = self.eat_literal().map(|L(t, abi)| (t, abi));
+            (t, Extern { kw: Token![extern], abi })
+        });
+        let mut tnext = self.eat_kw("fn").unwrap();
+        // the trivia before each keyword trails the keyword preceding it
+        let extern_ = extern_.map(|(t, e)| (e, std::mem::replace(&mut tnext, t)));
+        let safety = safety.map(|(t, s)| (s, std::mem::replace(&mut tnext, t)));
+        let async_ = tasync.map(|t| (Token![async], std::mem::replace(&mut tnext, t)));
+        let const_ = tconst.map(|t| (Token![const], std::mem::replace(&mut tnext, t)));
+        (tnext, FnQualifiers { const_, async_, safety, extern_ })
+    }
     fn parse_fn_params(&mut self) -> (Trivia, Parens<(Trivia, List<FnParam>)>) {
         self.eat_delim(Delimiter::Parens, |t0, mut this| {
             if let Some(last) = this.eat_eof() {
@@ -27,21 +121,11 @@ impl Parser<'_> {
             let mut tfirst = None;
             loop {
                 let (tattr, mut attrs) = this.parse_attrs(AttrKind::Outer).unwrap_or_default();
-                let L(tbeforepat, pat) = this.parse_pat();
-                attrs.push_trivia(tbeforepat);
-                let t1 = this.eat_punct(Punct::Colon).unwrap();
-                let L(t2, ty) = this.parse_ty();
+                let L(tbeforekind, kind) = this.parse_fn_param_kind();
+                attrs.push_trivia(tbeforekind);
                 let comma = this.eat_punct(Punct::Comma).map(|c| (c, Token![,]));
                 let has_comma = comma.is_some();
-                let p = FnParam {
-                    attrs,
-                    pat,
-                    t1,
-                    colon: Token![:],
-                    t2,
-                    ty,
-                    comma,
-                };
+                let p = FnParam { attrs, kind, comma };
 
                 if tfirst.is_none() {
                     tfirst = Some(tattr);
@@ -50,10 +134,9 @@ impl Parser<'_> {
                     list.push(tattr, p);
                 }
                 let eof = this.eat_eof();
-                if !has_comma || eof.is_some() {
-                    if let Some(tlast) = eof {
-                        list.push_trivia(tlast);
-                    }
+                assert!(has_comma || eof.is_some(), "expected `,` or `)`, found {:?}", this.token);
+                if let Some(tlast) = eof {
+                    list.push_trivia(tlast);
                     break (t0, Parens((tfirst.unwrap_or_default(), list)));
                 }
             }
@@ -63,7 +146,11 @@ impl Parser<'_> {
 
     pub(super) fn parse_fn_ret(&mut self) -> Option<(Trivia, FnRet)> {
         let t1 = self.eat_punct(Punct::RThinArrow)?;
-        let L(t2_5, ty) = self.parse_ty();
+        // `!` is stable as a return type only
+        let L(t2_5, ty) = match self.eat_punct(Punct::Bang) {
+            Some(t) => t << Ty::Never(Token![!]),
+            None => self.parse_ty(),
+        };
         Some((
             t1,
             FnRet {
@@ -98,19 +185,342 @@ impl Parser<'_> {
     }
     pub fn parse_item_ty_alias(&mut self, vis: Option<(Visibility, Trivia)>) -> TyAlias {
         let L(t1, name) = self.parse_ident();
-        let t2 = self.eat_punct(Punct::Eq).unwrap();
-        let L(t3, ty) = self.parse_ty();
-        let t4 = self.eat_punct(Punct::Semi).unwrap();
+        let generics = self.parse_generics().map(|L(t, g)| (t, g));
+        let bounds = self.parse_type_bounds();
+        let where_before = self.parse_where_clause().map(|L(t, w)| (t, w));
+        let ty = self.eat_punct(Punct::Eq).map(|t| {
+            let L(t2, ty) = self.parse_ty();
+            (t, Token![=], t2, ty)
+        });
+        let where_after = self.parse_where_clause().map(|L(t, w)| (t, w));
+        let t2 = self.eat_punct(Punct::Semi).unwrap();
         TyAlias {
             vis,
             kw: Token![type],
             t1,
             name,
-            t2,
-            eq: Token![=],
-            t3,
+            generics,
+            bounds,
+            where_before,
             ty,
-            t4,
+            where_after,
+            t2,
+            semi: Token![;],
+        }
+    }
+    fn parse_named_field(&mut self) -> L<NamedField> {
+        let attrs = self.parse_attrs(AttrKind::Outer);
+        let vis = self.parse_vis();
+        let L(tbeforename, name) = self.parse_ident();
+        let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforename);
+        let t1 = self.eat_punct(Punct::Colon).unwrap();
+        let L(t2, ty) = self.parse_ty();
+        t0 << NamedField { attrs, vis, name, t1, colon: Token![:], t2, ty }
+    }
+    fn parse_named_fields(&mut self) -> Option<L<Braces<NamedFields>>> {
+        self.eat_delim(Delimiter::Braces, |t0, mut this| {
+            let L(t1, fields) = this.parse_comma_sep_to_eof(Self::parse_named_field);
+            t0 << Braces(NamedFields { t1, fields })
+        })
+    }
+    fn parse_tuple_field(&mut self) -> L<TupleField> {
+        let attrs = self.parse_attrs(AttrKind::Outer);
+        let vis = self.parse_vis();
+        let L(tbeforety, ty) = self.parse_ty();
+        let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforety);
+        t0 << TupleField { attrs, vis, ty }
+    }
+    fn parse_tuple_fields(&mut self) -> Option<L<Parens<TupleFields>>> {
+        self.eat_delim(Delimiter::Parens, |t0, mut this| {
+            let L(t1, fields) = this.parse_comma_sep_to_eof(Self::parse_tuple_field);
+            t0 << Parens(TupleFields { t1, fields })
+        })
+    }
+    pub fn parse_item_struct(&mut self, vis: Option<(Visibility, Trivia)>) -> Struct {
+        let L(t1, name) = self.parse_ident();
+        let generics = self.parse_generics().map(|L(t, g)| (t, g));
+        let body = if let Some(L(t2, fields)) = self.parse_tuple_fields() {
+            let where_ = self.parse_where_clause().map(|L(t, w)| (t, w));
+            let t3 = self.eat_punct(Punct::Semi).unwrap();
+            StructBody::Tuple { t2, fields, where_, t3, semi: Token![;] }
+        } else {
+            let where_ = self.parse_where_clause().map(|L(t, w)| (t, w));
+            if let Some(t2) = self.eat_punct(Punct::Semi) {
+                StructBody::Unit { where_, t2, semi: Token![;] }
+            } else {
+                let L(t2, fields) = self.parse_named_fields().unwrap();
+                StructBody::Named { where_, t2, fields }
+            }
+        };
+        Struct {
+            vis,
+            kw: Token![struct],
+            t1: TriviaN::new(t1),
+            name,
+            generics,
+            body,
+        }
+    }
+    pub fn parse_item_union(&mut self, vis: Option<(Visibility, Trivia)>) -> Union {
+        let L(t1, name) = self.parse_ident();
+        let generics = self.parse_generics().map(|L(t, g)| (t, g));
+        let where_ = self.parse_where_clause().map(|L(t, w)| (t, w));
+        let L(t2, fields) = self.parse_named_fields().unwrap();
+        Union {
+            vis,
+            kw: Token![union],
+            t1: TriviaN::new(t1),
+            name,
+            generics,
+            where_,
+            t2,
+            fields,
+        }
+    }
+    fn parse_variant(&mut self) -> L<Variant> {
+        let attrs = self.parse_attrs(AttrKind::Outer);
+        let vis = self.parse_vis();
+        let L(tbeforename, name) = self.parse_ident();
+        let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforename);
+        let fields = if let Some(L(t, fields)) = self.parse_named_fields() {
+            Some((t, Fields::Named(fields)))
+        } else {
+            self.parse_tuple_fields().map(|L(t, fields)| (t, Fields::Tuple(fields)))
+        };
+        let discriminant = self.eat_punct(Punct::Eq).map(|t1| {
+            let L(t2, expr) = self.parse_expr();
+            (t1, Token![=], t2, expr)
+        });
+        t0 << Variant { attrs, vis, name, fields, discriminant }
+    }
+    pub fn parse_item_enum(&mut self, vis: Option<(Visibility, Trivia)>) -> Enum {
+        let L(t1, name) = self.parse_ident();
+        let generics = self.parse_generics().map(|L(t, g)| (t, g));
+        let where_ = self.parse_where_clause().map(|L(t, w)| (t, w));
+        let L(t2, variants) = self
+            .eat_delim(Delimiter::Braces, |t2, mut this| {
+                let L(t1, variants) = this.parse_comma_sep_to_eof(Self::parse_variant);
+                t2 << Braces(Variants { t1, variants })
+            })
+            .unwrap();
+        Enum {
+            vis,
+            kw: Token![enum],
+            t1: TriviaN::new(t1),
+            name,
+            generics,
+            where_,
+            t2,
+            variants,
+        }
+    }
+    fn check_trait(&self) -> bool {
+        self.check_ident("trait")
+            || (self.config.nightly && self.check_ident("auto") && self.peek2(|tt| tt.is_ident("trait")))
+            || (self.check_ident("unsafe")
+                && self.peek2(|tt| tt.is_ident("trait") || self.config.nightly && tt.is_ident("auto")))
+    }
+    fn check_default(&self) -> bool {
+        self.config.nightly
+            && self.check_ident("default")
+            && self.peek2(|tt| {
+                ["fn", "const", "type", "unsafe", "async", "extern", "impl"]
+                    .iter()
+                    .any(|kw| tt.is_ident(kw))
+            })
+    }
+    fn parse_assoc_item(&mut self) -> (Trivia, AssocItem) {
+        let attrs = self.parse_attrs(AttrKind::Outer);
+        let (t0, Item { attrs, kind }, default_) = if self.check_default() {
+            let tdefault = self.eat_kw("default").unwrap();
+            let (t0, attrs) = match attrs {
+                Some((t0, mut attrs)) => {
+                    attrs.push_trivia(tdefault);
+                    (t0, attrs)
+                }
+                None => (tdefault, List::default()),
+            };
+            let (t, mut item) = self.parse_items_without_attrs(None);
+            item.attrs = attrs;
+            (t0, item, Some((Token![default], t)))
+        } else {
+            let (t0, item) = self.parse_items_without_attrs(attrs);
+            (t0, item, None)
+        };
+        let kind = match kind {
+            ItemKind::Const(c) => AssocItemKind::Const(c),
+            ItemKind::TyAlias(ty) => AssocItemKind::Type(ty),
+            ItemKind::Fn(f) => AssocItemKind::Fn(f),
+            ItemKind::MacCall(mac, semi) => AssocItemKind::MacCall(mac, semi),
+            kind => panic!("not allowed as an associated item: {kind:?}"),
+        };
+        (t0, AssocItem { attrs, default_, kind })
+    }
+    /// Parses a trait or impl body.
+    pub(super) fn parse_assoc_items(&mut self) -> Option<L<Braces<AssocItems>>> {
+        self.eat_delim(Delimiter::Braces, |t0, mut this| {
+            let (t1, attrs) = this.parse_attrs(AttrKind::Inner).unwrap_or_default();
+            let mut items = AssocItems { t1, attrs, items: List::default() };
+            if let Some(tlast) = this.eat_eof() {
+                items.items.push_trivia(tlast);
+                return t0 << Braces(items);
+            }
+            let (t1, item) = this.parse_assoc_item();
+            items.attrs.push_trivia(t1);
+            items.items = List::single(item);
+            loop {
+                if let Some(tlast) = this.eat_eof() {
+                    items.items.push_trivia(tlast);
+                    return t0 << Braces(items);
+                }
+                let (t, item) = this.parse_assoc_item();
+                items.items.push(t, item);
+            }
+        })
+    }
+    fn parse_item_trait(
+        &mut self,
+        vis: Option<(Visibility, Trivia)>,
+        unsafe_: Option<(Token![unsafe], Trivia)>,
+        auto: Option<(Token![auto], Trivia)>,
+    ) -> ItemKind {
+        let L(t1, name) = self.parse_ident();
+        let generics = self.parse_generics().map(|L(t, g)| (t, g));
+        if self.config.nightly && let Some(t2) = self.eat_punct(Punct::Eq) {
+            assert!(unsafe_.is_none() && auto.is_none(), "trait aliases cannot be unsafe or auto");
+            let L(t3, bounds) = self.parse_type_param_bounds();
+            let where_ = self.parse_where_clause().map(|L(t, w)| (t, w));
+            let t4 = self.eat_punct(Punct::Semi).unwrap();
+            return ItemKind::TraitAlias(TraitAlias {
+                vis,
+                kw: Token![trait],
+                t1: TriviaN::new(t1),
+                name,
+                generics,
+                t2,
+                eq: Token![=],
+                t3,
+                bounds,
+                where_,
+                t4,
+                semi: Token![;],
+            });
+        }
+        let bounds = self.parse_type_bounds();
+        let where_ = self.parse_where_clause().map(|L(t, w)| (t, w));
+        let L(t2, items) = self.parse_assoc_items().unwrap();
+        ItemKind::Trait(Trait {
+            vis,
+            unsafe_,
+            auto,
+            kw: Token![trait],
+            t1: TriviaN::new(t1),
+            name,
+            generics,
+            bounds,
+            where_,
+            t2,
+            items,
+        })
+    }
+    fn check_impl(&self) -> bool {
+        let mut n = 0;
+        for kw in ["default", "unsafe", "const"] {
+            // `default impl` and `const impl` are nightly only
+            if (kw == "unsafe" || self.config.nightly) && self.peek_nth(n, |L(_, tt)| tt.is_ident(kw)) {
+                n += 1;
+            }
+        }
+        self.peek_nth(n, |L(_, tt)| tt.is_ident("impl"))
+    }
+    pub fn parse_item_impl(
+        &mut self,
+        default_: Option<(Token![default], Trivia)>,
+        unsafe_: Option<(Token![unsafe], Trivia)>,
+        const_: Option<(Token![const], Trivia)>,
+    ) -> Impl {
+        let generics = if self.choose_generics_over_qpath() {
+            self.parse_generics().map(|L(t, g)| (t, g))
+        } else {
+            None
+        };
+        let (tconst, tnegative) = if self.config.nightly {
+            (self.eat_kw("const"), self.eat_punct(Punct::Bang))
+        } else {
+            (None, None)
+        };
+        let L(tty, ty) = self.parse_ty();
+        let (t1, of_trait, self_ty) = if let Some(tfor) = self.eat_kw("for") {
+            let Ty::Path(qpath) = ty else {
+                panic!("expected a trait path, found {ty:?}")
+            };
+            let QPath { qself: None, path } = *qpath else {
+                panic!("expected a trait path, found a qualified path")
+            };
+            let L(t2, self_ty) = self.parse_ty();
+            let mut tnext = tty;
+            let negative = tnegative.map(|t| (Token![!], std::mem::replace(&mut tnext, t)));
+            let const_ = tconst.map(|t| (Token![const], std::mem::replace(&mut tnext, t)));
+            let of_trait = ImplOfTrait { const_, negative, path, t1: tfor, for_: Token![for], t2 };
+            (tnext, Some(of_trait), self_ty)
+        } else {
+            assert!(tconst.is_none() && tnegative.is_none(), "inherent impls cannot be const or negative");
+            (tty, None, ty)
+        };
+        let where_ = self.parse_where_clause().map(|L(t, w)| (t, w));
+        let L(t2, items) = self.parse_assoc_items().unwrap();
+        Impl {
+            default_,
+            unsafe_,
+            const_,
+            kw: Token![impl],
+            generics,
+            t1,
+            of_trait,
+            self_ty,
+            where_,
+            t2,
+            items,
+        }
+    }
+    fn parse_use_tree(&mut self) -> L<UseTree> {
+        if let Some(t0) = self.eat_punct(Punct::Star) {
+            t0 << UseTree::Glob(Token![*])
+        } else if let Some(group) = self.eat_delim(Delimiter::Braces, |t0, mut this| {
+            let L(t1, trees) = this.parse_comma_sep_to_eof(Self::parse_use_tree);
+            t0 << UseTree::Group(Braces(UseGroup { t1, trees }))
+        }) {
+            group
+        } else {
+            let L(t0, ident) = self.parse_ident();
+            let tree = if let Some(t1) = self.eat_punct(Punct::ColonColon) {
+                let L(t2, tree) = self.parse_use_tree();
+                UseTree::Path { ident, t1, colon2: Token![::], t2, tree: Box::new(tree) }
+            } else if let Some(t1) = self.eat_kw("as") {
+                let L(t2, rename) = self.parse_ident();
+                UseTree::Rename { ident, t1, as_: Token![as], t2, rename }
+            } else {
+                UseTree::Name(ident)
+            };
+            t0 << tree
+        }
+    }
+    pub fn parse_item_use(&mut self, vis: Option<(Visibility, Trivia)>) -> Use {
+        let (t1, leading_colon, tree) = if let Some(t1) = self.eat_punct(Punct::ColonColon) {
+            let L(t, tree) = self.parse_use_tree();
+            (t1, Some((Token![::], t)), tree)
+        } else {
+            let L(t1, tree) = self.parse_use_tree();
+            (t1, None, tree)
+        };
+        let t2 = self.eat_punct(Punct::Semi).unwrap();
+        Use {
+            vis,
+            kw: Token![use],
+            t1,
+            leading_colon,
+            tree,
+            t2,
             semi: Token![;],
         }
     }
@@ -118,8 +528,10 @@ impl Parser<'_> {
         let L(t1, name) = self.parse_ident();
         let t2 = self.eat_punct(Punct::Colon).unwrap();
         let L(t3, ty) = self.parse_ty();
-        let t4 = self.eat_punct(Punct::Eq).unwrap();
-        let L(t5, expr) = self.parse_expr();
+        let init = self.eat_punct(Punct::Eq).map(|t4| {
+            let L(t5, expr) = self.parse_expr();
+            ItemInit { t4, eq: Token![=], t5, expr }
+        });
         let t6 = self.eat_punct(Punct::Semi).unwrap();
         Const {
             vis,
@@ -130,41 +542,145 @@ impl Parser<'_> {
             colon: Token![:],
             t3,
             ty,
-            t4,
-            eq: Token![=],
-            t5,
-            expr,
+            init,
             t6,
             semi: Token![;],
         }
     }
-    pub fn parse_item_static(&mut self, vis: Option<(Visibility, Trivia)>) -> Static {
-        let L(t1, name) = self.parse_ident();
+    fn check_static(&self) -> bool {
+        self.check_ident("static")
+            || ((self.check_ident("unsafe") || self.check_ident("safe"))
+                && self.peek2(|tt| tt.is_ident("static")))
+    }
+    pub fn parse_item_static(
+        &mut self,
+        vis: Option<(Visibility, Trivia)>,
+        safety: Option<(Safety, Trivia)>,
+    ) -> Static {
+        let tmut = self.eat_kw("mut");
+        let L(t, name) = self.parse_ident();
+        let (t1, mut_) = match tmut {
+            Some(t1) => (t1, Some((Token![mut], TriviaN::new(t)))),
+            None => (t, None),
+        };
         let t2 = self.eat_punct(Punct::Colon).unwrap();
         let L(t3, ty) = self.parse_ty();
-        let t4 = self.eat_punct(Punct::Eq).unwrap();
-        let L(t5, expr) = self.parse_expr();
+        let init = self.eat_punct(Punct::Eq).map(|t4| {
+            let L(t5, expr) = self.parse_expr();
+            ItemInit { t4, eq: Token![=], t5, expr }
+        });
         let t6 = self.eat_punct(Punct::Semi).unwrap();
         Static {
             vis,
-            t1: TriviaN::new(t1),
+            safety,
             kw: Token![static],
+            t1: TriviaN::new(t1),
+            mut_,
             name,
             t2,
             colon: Token![:],
             t3,
             ty,
-            t4,
-            eq: Token![=],
-            t5,
-            expr,
+            init,
             t6,
             semi: Token![;],
         }
     }
+    pub fn parse_item_extern_crate(&mut self, vis: Option<(Visibility, Trivia)>) -> ExternCrate {
+        let t1 = self.eat_kw("crate").unwrap();
+        let L(t2, name) = self.parse_ident();
+        let rename = self.eat_kw("as").map(|t| {
+            let L(t2, rename) = self.parse_ident();
+            (t, Token![as], t2, rename)
+        });
+        let t3 = self.eat_punct(Punct::Semi).unwrap();
+        ExternCrate {
+            vis,
+            kw: Token![extern],
+            t1,
+            crate_: Token![crate],
+            t2,
+            name,
+            rename,
+            t3,
+            semi: Token![;],
+        }
+    }
+    fn check_foreign_mod(&self) -> bool {
+        let n = usize::from(self.check_ident("unsafe"));
+        if !self.peek_nth(n, |L(_, tt)| tt.is_ident("extern")) {
+            return false;
+        }
+        let n = n + 1;
+        let n = n + usize::from(self.peek_nth(n, |L(_, tt)| matches!(tt, TokenTree::Literal(_))));
+        self.peek_nth(n, |L(_, tt)| tt.is_delim(Delimiter::Braces))
+    }
+    fn parse_foreign_item(&mut self) -> (Trivia, ForeignItem) {
+        let (t0, Item { attrs, kind }) = self.parse_item();
+        let kind = match kind {
+            ItemKind::Fn(f) => ForeignItemKind::Fn(f),
+            ItemKind::Static(s) => ForeignItemKind::Static(s),
+            ItemKind::TyAlias(ty) => {
+                assert!(self.config.nightly, "extern types are nightly only");
+                ForeignItemKind::Type(ty)
+            }
+            ItemKind::MacCall(mac, semi) => ForeignItemKind::MacCall(mac, semi),
+            kind => panic!("not allowed in an extern block: {kind:?}"),
+        };
+        (t0, ForeignItem { attrs, kind })
+    }
+    fn parse_foreign_items(&mut self) -> L<Braces<ForeignItems>> {
+        self.eat_delim(Delimiter::Braces, |t0, mut this| {
+            let (t1, attrs) = this.parse_attrs(AttrKind::Inner).unwrap_or_default();
+            let mut items = ForeignItems { t1, attrs, items: List::default() };
+            if let Some(tlast) = this.eat_eof() {
+                items.items.push_trivia(tlast);
+                return t0 << Braces(items);
+            }
+            let (t1, item) = this.parse_foreign_item();
+            items.attrs.push_trivia(t1);
+            items.items = List::single(item);
+            loop {
+                if let Some(tlast) = this.eat_eof() {
+                    items.items.push_trivia(tlast);
+                    return t0 << Braces(items);
+                }
+                let (t, item) = this.parse_foreign_item();
+                items.items.push(t, item);
+            }
+        })
+        .unwrap()
+    }
+    pub fn parse_item_foreign_mod(&mut self, unsafe_: Option<(Token![unsafe], Trivia)>) -> ForeignMod {
+        let abi = self.eat_literal().map(|L(t, abi)| (t, abi));
+        let L(t1, items) = self.parse_foreign_items();
+        ForeignMod {
+            unsafe_,
+            extern_: Extern { kw: Token![extern], abi },
+            t1,
+            items,
+        }
+    }
     pub(in super) fn parse_items_without_attrs(&mut self, attrs: Option<(Trivia, List<Attribute>)>) -> (Trivia, Item) {
         let vis = self.parse_vis();
-        if let Some(tbeforemod) = self.eat_kw("mod") {
+        if self.check_impl() {
+            assert!(vis.is_none(), "impls cannot have a visibility");
+            let tdefault = self.eat_kw("default");
+            let tunsafe = self.eat_kw("unsafe");
+            let tconst = self.eat_kw("const");
+            let mut tnext = self.eat_kw("impl").unwrap();
+            // the trivia before each keyword trails the keyword preceding it
+            let const_ = tconst.map(|t| (Token![const], std::mem::replace(&mut tnext, t)));
+            let unsafe_ = tunsafe.map(|t| (Token![unsafe], std::mem::replace(&mut tnext, t)));
+            let default_ = tdefault.map(|t| (Token![default], std::mem::replace(&mut tnext, t)));
+            let (t0, attrs, _) = juggle_trivia(attrs, None, tnext);
+            let kind = ItemKind::Impl(self.parse_item_impl(default_, unsafe_, const_));
+            (t0, Item { attrs, kind })
+        } else if let Some(tbeforeuse) = self.eat_kw("use") {
+            let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforeuse);
+            let kind = ItemKind::Use(self.parse_item_use(vis));
+            (t0, Item { attrs, kind })
+        } else if let Some(tbeforemod) = self.eat_kw("mod") {
             let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforemod);
             let kind = ItemKind::Mod(self.parse_item_mod(vis));
             (t0, Item { attrs, kind })
@@ -172,37 +688,154 @@ impl Parser<'_> {
             let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforetype);
             let kind = ItemKind::TyAlias(self.parse_item_ty_alias(vis));
             (t0, Item { attrs, kind })
-        } else if let Some(tbeforefn) = self.eat_kw("fn") {
-            // TODO parse leading modifiers (unsafe, const, extern)
+        } else if self.check_fn() {
+            let (tbeforefn, qualifiers) = self.parse_fn_qualifiers();
             let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforefn);
             let L(t1, name) = self.parse_ident();
+            let generics = self.parse_generics().map(|L(t, g)| (t, g));
             let (t2, params) = self.parse_fn_params();
             let ret = self.parse_fn_ret();
-            let L(t3, block) = self.parse_block();
+            let where_ = self.parse_where_clause().map(|L(t, w)| (t, w));
+            let (t3, body) = if let Some(t3) = self.eat_punct(Punct::Semi) {
+                (t3, FnBody::Semi(Token![;]))
+            } else {
+                // only foreign fns can be variadic on stable
+                assert!(
+                    self.config.nightly
+                        || !params.0.1.iter().any(|p| matches!(p.kind, FnParamKind::Variadic { .. })),
+                    "C-variadic functions with a body are nightly only"
+                );
+                let L(t3, block) = self.parse_block();
+                (t3, FnBody::Block(block))
+            };
             let kind = ItemKind::Fn(Fn {
                 vis,
+                qualifiers,
                 kw: Token![fn],
                 t1: TriviaN::new(t1),
                 name,
+                generics,
                 t2,
                 params,
                 ret,
+                where_,
                 t3,
-                block,
+                body,
             });
             (t0, Item { attrs, kind })
+        } else if self.check_ident("extern") && self.peek2(|tt| tt.is_ident("crate")) {
+            let tbeforeextern = self.eat_kw("extern").unwrap();
+            let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforeextern);
+            let kind = ItemKind::ExternCrate(self.parse_item_extern_crate(vis));
+            (t0, Item { attrs, kind })
+        } else if self.check_foreign_mod() {
+            assert!(vis.is_none(), "extern blocks cannot have a visibility");
+            let tunsafe = self.eat_kw("unsafe");
+            let mut tnext = self.eat_kw("extern").unwrap();
+            let unsafe_ = tunsafe.map(|t| (Token![unsafe], std::mem::replace(&mut tnext, t)));
+            let (t0, attrs, _) = juggle_trivia(attrs, None, tnext);
+            let kind = ItemKind::ForeignMod(self.parse_item_foreign_mod(unsafe_));
+            (t0, Item { attrs, kind })
+        } else if self.check_macro_rules() {
+            assert!(vis.is_none(), "macro_rules! cannot have a visibility");
+            let tbeforekw = self.eat_kw("macro_rules").unwrap();
+            let (t0, attrs, _) = juggle_trivia(attrs, None, tbeforekw);
+            let kind = ItemKind::MacroRules(self.parse_item_macro_rules());
+            (t0, Item { attrs, kind })
+        } else if self.config.nightly
+            && self.check_ident("macro")
+            && self.peek2(|tt| matches!(tt, TokenTree::Ident(_)))
+        {
+            let tbeforemacro = self.eat_kw("macro").unwrap();
+            let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforemacro);
+            let kind = ItemKind::Macro2(self.parse_item_macro2(vis));
+            (t0, Item { attrs, kind })
         } else if let Some(tbeforeconst) = self.eat_kw("const") {
             let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforeconst);
             let kind = ItemKind::Const(self.parse_item_const(vis));
             (t0, Item { attrs, kind })
-        } else if let Some(tbeforestatic) = self.eat_kw("static") {
-            let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforestatic);
-            let kind = ItemKind::Static(self.parse_item_static(vis));
+        } else if self.check_static() {
+            let safety = if let Some(t) = self.eat_kw("unsafe") {
+                Some((t, Safety::Unsafe(Token![unsafe])))
+            } else {
+                self.eat_kw("safe").map(|t| (t, Safety::Safe(Token![safe])))
+            };
+            let mut tnext = self.eat_kw("static").unwrap();
+            // the trivia before each keyword trails the keyword preceding it
+            let safety = safety.map(|(t, s)| (s, std::mem::replace(&mut tnext, t)));
+            let (t0, attrs, vis) = juggle_trivia(attrs, vis, tnext);
+            let kind = ItemKind::Static(self.parse_item_static(vis, safety));
+            (t0, Item { attrs, kind })
+        } else if self.check_trait() {
+            let tunsafe = self.eat_kw("unsafe");
+            let tauto = self.eat_kw("auto");
+            let mut tnext = self.eat_kw("trait").unwrap();
+            // the trivia before each keyword trails the keyword preceding it
+            let auto = tauto.map(|t| (Token![auto], std::mem::replace(&mut tnext, t)));
+            let unsafe_ = tunsafe.map(|t| (Token![unsafe], std::mem::replace(&mut tnext, t)));
+            let (t0, attrs, vis) = juggle_trivia(attrs, vis, tnext);
+            let kind = self.parse_item_trait(vis, unsafe_, auto);
+            (t0, Item { attrs, kind })
+        } else if let Some(tbeforestruct) = self.eat_kw("struct") {
+            let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforestruct);
+            let kind = ItemKind::Struct(self.parse_item_struct(vis));
+            (t0, Item { attrs, kind })
+        } else if self.check_ident("union") && self.peek2(|tt| matches!(tt, TokenTree::Ident(_))) {
+            let tbeforeunion = self.eat_kw("union").unwrap();
+            let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforeunion);
+            let kind = ItemKind::Union(self.parse_item_union(vis));
+            (t0, Item { attrs, kind })
+        } else if let Some(tbeforeenum) = self.eat_kw("enum") {
+            let (t0, attrs, vis) = juggle_trivia(attrs, vis, tbeforeenum);
+            let kind = ItemKind::Enum(self.parse_item_enum(vis));
+            (t0, Item { attrs, kind })
+        } else if self.check_macro_call() {
+            assert!(vis.is_none(), "macro calls cannot have a visibility");
+            let L(tbeforepath, path) = self.parse_path(PathStyle::Mod);
+            let (t0, attrs, _) = juggle_trivia(attrs, None, tbeforepath);
+            let mac = self.parse_rest_of_macro_call(path);
+            let semi = self.eat_punct(Punct::Semi).map(|t| (t, Token![;]));
+            assert!(
+                semi.is_some() || mac.inner.delimiter() == Delimiter::Braces,
+                "macro calls without braces must end with `;`"
+            );
+            let kind = ItemKind::MacCall(mac, semi);
             (t0, Item { attrs, kind })
         } else {
             unimplemented!("{:?}", self.token)
         }
     }
+    /// Whether the tokens ahead start an item rather than an expression, used to tell
+    /// the two apart in statement position.
+    pub(super) fn check_item_start(&self) -> bool {
+        const ALWAYS: &[&str] = &[
+            "pub", "fn", "mod", "use", "struct", "enum", "trait", "impl", "extern", "type",
+        ];
+        if ALWAYS.iter().any(|kw| self.check_ident(kw)) {
+            true
+        } else if self.check_ident("const") || self.check_ident("static") {
+            // `const {}` blocks and `static ||` coroutines
+            !self.peek2(|tt| {
+                tt.is_delim(Delimiter::Braces)
+                    || tt.is_punct(Punct::Or)
+                    || tt.is_punct(Punct::OrOr)
+                    || tt.is_ident("move")
+                    || tt.is_ident("async")
+            })
+        } else if self.check_ident("unsafe") {
+            !self.peek2(|tt| tt.is_delim(Delimiter::Braces))
+        } else if self.check_ident("async") {
+            self.peek2(|tt| tt.is_ident("fn") || tt.is_ident("unsafe") || tt.is_ident("extern"))
+        } else if self.check_ident("union") || self.config.nightly && self.check_ident("macro") {
+            self.peek2(|tt| matches!(tt, TokenTree::Ident(_)))
+        } else if self.config.nightly && self.check_ident("auto") {
+            self.peek2(|tt| tt.is_ident("trait"))
+        } else if self.check_ident("macro_rules") {
+            self.check_macro_rules()
+        } else {
+            false
+        }
+    }
     pub fn parse_item(&mut self) -> (Trivia, Item) {
         let attrs = self.parse_attrs(AttrKind::Outer);
         self.parse_items_without_attrs(attrs)
@@ -0,0 +1,172 @@
+use crate::prelude::*;
+
+impl<'src> super::Parser<'src> {
+    /// Parses the group at the current token with `f`, keeping its delimiter.
+    fn parse_delimited<T>(&mut self, f: impl FnOnce(&mut Parser<'src>) -> T) -> Option<L<Delimited<T>>> {
+        let TokenTree::Group(group) = &self.token.1 else {
+            return None;
+        };
+        let delim = group.delimiter();
+        self.eat_delim(delim, |t0, mut this| {
+            let x = f(&mut this);
+            t0 << match delim {
+                Delimiter::Braces => Delimited::Braces(Braces(x)),
+                Delimiter::Brackets => Delimited::Brackets(Brackets(x)),
+                Delimiter::Parens => Delimited::Parens(Parens(x)),
+            }
+        })
+    }
+
+    fn check_rep_op(&self) -> bool {
+        self.peek(|tt| {
+            tt.is_punct(Punct::Star) || tt.is_punct(Punct::Plus) || tt.is_punct(Punct::Question)
+        })
+    }
+
+    fn parse_rep_op(&mut self) -> L<RepOp> {
+        if let Some(t) = self.eat_punct(Punct::Star) {
+            t << RepOp::ZeroOrMore(Token![*])
+        } else if let Some(t) = self.eat_punct(Punct::Plus) {
+            t << RepOp::OneOrMore(Token![+])
+        } else {
+            let t = self.eat_punct(Punct::Question).expect("expected `*`, `+` or `?`");
+            t << RepOp::ZeroOrOne(Token![?])
+        }
+    }
+
+    fn parse_matcher_tree(&mut self) -> L<MatcherTree> {
+        if self.check_punct(Punct::Dollar) {
+            if self.peek2(|tt| tt.is_delim(Delimiter::Parens)) {
+                let t0 = self.bump().0;
+                let L(t1, inner) = self.parse_delimited(Self::parse_matcher).unwrap();
+                // `$(..)+` has no separator, even though `+` could be one
+                let sep = (!self.check_rep_op()).then(|| {
+                    let L(t, tt) = self.bump();
+                    (t, tt)
+                });
+                let L(t2, op) = self.parse_rep_op();
+                return t0 << MatcherTree::Repetition(Repetition {
+                    dollar: Token![$],
+                    t1,
+                    inner,
+                    sep,
+                    t2,
+                    op,
+                });
+            }
+            if self.peek2(|tt| matches!(tt, TokenTree::Ident(_)))
+                && self.peek3(|tt| tt.is_punct(Punct::Colon))
+            {
+                let t0 = self.bump().0;
+                let L(t1, name) = self.parse_ident();
+                let t2 = self.eat_punct(Punct::Colon).unwrap();
+                let L(t3, frag) = self.parse_ident();
+                return t0 << MatcherTree::MetaVar(MetaVar {
+                    dollar: Token![$],
+                    t1,
+                    name,
+                    t2,
+                    colon: Token![:],
+                    t3,
+                    frag,
+                });
+            }
+        }
+        if let Some(group) = self.parse_delimited(Self::parse_matcher) {
+            group.map(MatcherTree::Group)
+        } else {
+            self.bump().map(MatcherTree::Token)
+        }
+    }
+
+    fn parse_matcher(&mut self) -> Matcher {
+        let mut tokens = List::default();
+        if let Some(tlast) = self.eat_eof() {
+            tokens.push_trivia(tlast);
+            return Matcher { t1: Trivia::default(), tokens };
+        }
+        let L(t1, tree) = self.parse_matcher_tree();
+        tokens.push_value(tree);
+        loop {
+            if let Some(tlast) = self.eat_eof() {
+                tokens.push_trivia(tlast);
+                return Matcher { t1, tokens };
+            }
+            let L(t, tree) = self.parse_matcher_tree();
+            tokens.push(t, tree);
+        }
+    }
+
+    fn parse_macro_rule(&mut self) -> L<MacroRule> {
+        let L(t0, matcher) = self.parse_delimited(Self::parse_matcher).expect("expected a matcher");
+        let t1 = self.eat_punct(Punct::RFatArrow).unwrap();
+        let L(t2, transcriber) = self.eat_delimited().expect("expected a transcriber");
+        t0 << MacroRule { matcher, t1, arrow: Token![=>], t2, transcriber }
+    }
+
+    /// Parses rules separated by `sep` up to the end of the group.
+    fn parse_macro_rule_list<S>(&mut self, sep: Punct, s: fn() -> S) -> L<SeparatedList<MacroRule, S>> {
+        let mut builder = SeparatedListBuilder::new();
+        let tlast = loop {
+            if let Some(tlast) = self.eat_eof() {
+                break tlast;
+            }
+            let L(t, rule) = self.parse_macro_rule();
+            builder.push_value(t, rule);
+            if let Some(tlast) = self.eat_eof() {
+                break tlast;
+            }
+            let t = self.eat_punct(sep).unwrap();
+            builder.push_sep(t, s());
+        };
+        let L(t1, mut rules) = builder.build();
+        rules.push_trivia(tlast);
+        t1 << rules
+    }
+
+    pub(super) fn check_macro_rules(&self) -> bool {
+        self.check_ident("macro_rules")
+            && self.peek2(|tt| tt.is_punct(Punct::Bang))
+            && self.peek3(|tt| matches!(tt, TokenTree::Ident(_)))
+    }
+
+    /// Parses the rest of `macro_rules! name { .. }` after the `macro_rules` keyword.
+    pub(super) fn parse_item_macro_rules(&mut self) -> MacroRules {
+        let t1 = self.eat_punct(Punct::Bang).unwrap();
+        let L(t2, name) = self.parse_ident();
+        let L(t3, body) = self
+            .parse_delimited(|this| {
+                let L(t1, rules) = this.parse_macro_rule_list(Punct::Semi, || Token![;]);
+                MacroRulesBody { t1, rules }
+            })
+            .expect("expected macro rules");
+        let semi = self.eat_punct(Punct::Semi).map(|t| (t, Token![;]));
+        assert!(
+            semi.is_some() || body.delimiter() == Delimiter::Braces,
+            "macro_rules! without braces must end with `;`"
+        );
+        MacroRules { kw: Token![macro_rules], t1, bang: Token![!], t2, name, t3, body, semi }
+    }
+
+    /// Parses the rest of `macro name ..` after the `macro` keyword.
+    pub(super) fn parse_item_macro2(&mut self, vis: Option<(Visibility, Trivia)>) -> Macro2 {
+        let L(t1, name) = self.parse_ident();
+        let (t2, body) = if let Some(L(t2, matcher)) =
+            self.eat_delim(Delimiter::Parens, |t, mut this| t << this.parse_matcher())
+        {
+            let Some(L(t1, Delimited::Braces(transcriber))) = self.eat_delimited() else {
+                panic!("expected a braced macro body");
+            };
+            (t2, Macro2Body::Single { matcher: Parens(matcher), t1, transcriber })
+        } else {
+            let L(t2, rules) = self
+                .eat_delim(Delimiter::Braces, |t, mut this| {
+                    let L(t1, rules) = this.parse_macro_rule_list(Punct::Comma, || Token![,]);
+                    t << Macro2Rules { t1, rules }
+                })
+                .expect("expected macro rules");
+            (t2, Macro2Body::Rules(Braces(rules)))
+        };
+        Macro2 { vis, kw: Token![macro], t1: TriviaN::new(t1), name, t2, body }
+    }
+}
@@ -1,6 +1,10 @@
 use std::ops::Shl;
 use std::{mem, vec};
 
+use ra_ap_rustc_lexer::{strip_shebang, FrontmatterAllowed, TokenKind};
+use smol_str::SmolStr;
+
+use crate::lex::tokenize;
 use crate::parse::attr::AttrKind;
 use crate::parse::glue::Gluer;
 use crate::prelude::*;
@@ -10,11 +14,13 @@ mod expr;
 mod generics;
 mod glue;
 mod item;
+mod macros;
 mod pat;
 mod path;
 mod stmt;
 mod ty;
 pub use expr::Precedence;
+pub use path::PathStyle;
 
 #[derive(Default, Clone, Debug, Print, Walk)]
 pub struct TokenStream {
@@ -23,7 +29,7 @@ pub struct TokenStream {
 }
 
 impl TokenStream {
-    pub fn into_iter(self) -> TokenStreamIter {
+    pub fn into_token_iter(self) -> TokenStreamIter {
         let (v, last) = self.tokens.into_parts();
         TokenStreamIter {
             tprev: self.t1,
@@ -79,9 +85,16 @@ pub enum TokenTree {
     Punct(Punct),
     Ident(Ident),
     RawIdent(Ident),
+    // k#ident
+    RawKeyword(Ident),
+    // the `foo` of `foo#bar`, `foo"bar"` or `foo'b'`
+    ReservedPrefix(Ident),
     Lifetime(Ident),
     RawLifetime(Ident),
+    // the `'foo` of `'foo#bar`
+    ReservedLifetimePrefix(Ident),
     Literal(Literal),
+    GuardedStr(GuardedStr),
     Eof,
 }
 
@@ -177,6 +190,22 @@ pub enum Punct {
     LThinArrow,
 }
 
+impl Punct {
+    /// Split a glued punctuation into its first character and the rest, e.g. `&&` into `&` and `&`.
+    pub fn split_first(self) -> Option<(Punct, Punct)> {
+        Some(match self {
+            Punct::AndAnd => (Punct::And, Punct::And),
+            Punct::OrOr => (Punct::Or, Punct::Or),
+            Punct::LtEq => (Punct::Lt, Punct::Eq),
+            Punct::LtLtEq => (Punct::Lt, Punct::LtEq),
+            Punct::GtEq => (Punct::Gt, Punct::Eq),
+            Punct::GtGtEq => (Punct::Gt, Punct::GtEq),
+            Punct::LThinArrow => (Punct::Lt, Punct::Minus),
+            _ => return None,
+        })
+    }
+}
+
 impl Visit for Punct {
     fn visit<P: crate::passes::Pass + ?Sized>(&mut self, _: &mut P) {}
 }
@@ -223,7 +252,7 @@ impl<T: Visit> Visit for WithLeadingTrivia<T> {
     }
 }
 
-pub use WithLeadingTrivia as L;
+pub use self::WithLeadingTrivia as L;
 
 // I think someone will hate this :3
 impl<T> Shl<T> for Trivia {
@@ -233,22 +262,43 @@ impl<T> Shl<T> for Trivia {
     }
 }
 
+/// Options for [`parse_with_config`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ParserConfig {
+    /// Accept syntax that needs a `#![feature]`. Without it, none of the following is recognized:
+    ///
+    /// - expressions: `do yeet`, `builtin #`, postfix `.use` and `.match`, `try` and `gen`
+    ///   blocks, `yield`, `become`, and `static` or `for<..>` closures
+    /// - patterns: `box`, `!`, guards outside of match arms and `const` blocks
+    /// - types: `&pin`, `unsafe<..>` binders, `!` outside of return types, and the `const`,
+    ///   `~const`, `async` and `!` bound modifiers
+    /// - items: `default`, `const` and negative impls, `default` associated items, `auto`
+    ///   traits, trait aliases, `macro` definitions, extern types and C-variadic fns with a body
+    /// - `k#` raw keywords and the frontmatter
+    pub nightly: bool,
+}
+
 pub struct Parser<'src> {
     tokens: Box<dyn TokenIterator + 'src>,
     token: WithLeadingTrivia<TokenTree>,
+    config: ParserConfig,
 }
 
 impl<'src> Parser<'src> {
-    fn create(x: impl TokenIterator + 'src) -> Self {
+    fn create(x: impl TokenIterator + 'src, config: ParserConfig) -> Self {
         let mut p = Parser {
             tokens: Box::new(x),
             token: Trivia::default() << TokenTree::Eof,
+            config,
         };
         p.bump();
         p
     }
     pub fn new(s: &'src str) -> Self {
-        Parser::create(Gluer::new(crate::lex::tokenize(s)))
+        Parser::with_config(s, ParserConfig::default())
+    }
+    pub fn with_config(s: &'src str, config: ParserConfig) -> Self {
+        Parser::create(Gluer::new(crate::lex::tokenize(s, FrontmatterAllowed::No)), config)
     }
     pub fn bump(&mut self) -> WithLeadingTrivia<TokenTree> {
         mem::replace(&mut self.token, self.tokens.next())
@@ -257,6 +307,7 @@ impl<'src> Parser<'src> {
         Parser {
             tokens: self.tokens.snapshot(),
             token: self.token.clone(),
+            config: self.config,
         }
     }
     pub fn peek(&self, f: impl FnOnce(&TokenTree) -> bool) -> bool {
@@ -279,6 +330,34 @@ impl<'src> Parser<'src> {
     pub fn eat_punct(&mut self, punct: Punct) -> Option<Trivia> {
         self.check_punct(punct).then(|| self.bump().0)
     }
+    /// Like [`Parser::check_punct`], but also accepts a glued punctuation that starts with `punct`.
+    #[must_use]
+    pub fn check_punct_split(&self, punct: Punct) -> bool {
+        matches!(self.token.1, TokenTree::Punct(got) if got == punct || got.split_first().is_some_and(|(first, _)| first == punct))
+    }
+    /// Like [`Parser::eat_punct`], but splits `punct` off a glued punctuation if needed,
+    /// leaving the rest of it as the current token.
+    pub fn eat_punct_split(&mut self, punct: Punct) -> Option<Trivia> {
+        if self.check_punct(punct) {
+            return Some(self.bump().0);
+        }
+        let TokenTree::Punct(got) = self.token.1 else {
+            return None;
+        };
+        let (first, rest) = got.split_first()?;
+        (first == punct).then(|| {
+            self.token.1 = TokenTree::Punct(rest);
+            self.token.0.take()
+        })
+    }
+    /// Whether the token after the current one is `punct` (or starts with it) with no trivia in between.
+    #[must_use]
+    pub fn peek2_joint_punct(&self, punct: Punct) -> bool {
+        self.peek_nth(1, |L(t, tt)| {
+            t.is_empty()
+                && matches!(tt, TokenTree::Punct(got) if *got == punct || got.split_first().is_some_and(|(first, _)| first == punct))
+        })
+    }
     pub fn eat_delimited(&mut self) -> Option<WithLeadingTrivia<Delimited<TokenStream>>> {
         self.eat(|tt| matches!(tt, TokenTree::Group(_)))
             .map(|tt| tt.map(|tt| tt.into_group().unwrap()))
@@ -291,7 +370,7 @@ impl<'src> Parser<'src> {
         if let Some(L(t, TokenTree::Group(tokens))) =
             self.eat(|t| matches!(t, TokenTree::Group(tokens) if tokens.delimiter() == delim))
         {
-            let p = Parser::create(tokens.into_inner().into_iter());
+            let p = Parser::create(tokens.into_inner().into_token_iter(), self.config);
             Some(f(t, p))
         } else {
             None
@@ -337,30 +416,27 @@ impl<'src> Parser<'src> {
 
     pub fn parse_ident(&mut self) -> L<Ident> {
         let L(t, tok) = self.bump();
-        let TokenTree::Ident(id) = tok else {
-            panic!("expected ident")
+        let id = match tok {
+            TokenTree::Ident(id) => id,
+            TokenTree::RawKeyword(id) if self.config.nightly => id,
+            _ => panic!("expected ident"),
         };
         t << id
     }
 
-    pub fn parse_path_segment(&mut self) -> L<PathSegment> {
-        let L(t0, ident) = self.parse_ident();
-        // TODO
-        t0 << PathSegment { ident, args: None }
-    }
-    pub fn parse_path(&mut self) -> L<Path> {
+    pub fn parse_path(&mut self, style: PathStyle) -> L<Path> {
         let (t0, leading_colon, seg1) = if let Some(t0) = self.eat_punct(Punct::ColonColon) {
-            let L(t1, seg1) = self.parse_path_segment();
+            let L(t1, seg1) = self.parse_path_segment(style);
             (t0, Some((Token![::], t1)), seg1)
         } else {
-            let L(t0, seg1) = self.parse_path_segment();
+            let L(t0, seg1) = self.parse_path_segment(style);
             (t0, None, seg1)
         };
 
         let mut rest = vec![];
 
         while let Some(t1) = self.eat_punct(Punct::ColonColon) {
-            let L(t2, seg) = self.parse_path_segment();
+            let L(t2, seg) = self.parse_path_segment(style);
             rest.push((t1, Token![::], t2, seg));
         }
 
@@ -371,21 +447,21 @@ impl<'src> Parser<'src> {
         }
     }
 
-    pub fn parse_qpath(&mut self) -> L<QPath> {
+    pub fn parse_qpath(&mut self, style: PathStyle) -> L<QPath> {
         if let Some(t0) = self.eat_punct(Punct::Lt) {
             let L(t1, selfty) = self.parse_ty();
             let as_ = if let Some(L(t2, _)) = self.eat_ident("as") {
-                let L(t3, p) = self.parse_path();
+                let L(t3, p) = self.parse_path(PathStyle::Type);
                 Some((t2, Token![as], t3, p))
             } else {
                 None
             };
-            let tlast = self.eat_punct(Punct::Gt).unwrap();
-            let L(tprev, path) = self.parse_path();
+            let tlast = self.eat_punct_split(Punct::Gt).unwrap();
+            let L(tprev, path) = self.parse_path(style);
 
             t0 << QPath {
                 qself: Some((
-                    (QSelf {
+                    Box::new(QSelf {
                         left: Token![<],
                         t1,
                         ty: Box::new(selfty),
@@ -398,16 +474,28 @@ impl<'src> Parser<'src> {
                 path,
             }
         } else {
-            self.parse_path().map(|path| QPath { qself: None, path })
+            self.parse_path(style).map(|path| QPath { qself: None, path })
         }
     }
 
+    /// Whether the parens after `pub` restrict the visibility, as opposed to
+    /// the parenthesized type in `struct S(pub (u8, u8));`.
+    fn check_vis_restriction(&self) -> bool {
+        self.snapshot()
+            .eat_delim(Delimiter::Parens, |_, this| {
+                this.check_ident("in")
+                    || (["crate", "self", "super"].iter().any(|kw| this.check_ident(kw))
+                        && this.peek2(|tt| matches!(tt, TokenTree::Eof)))
+            })
+            .unwrap_or(false)
+    }
     pub fn parse_vis(&mut self) -> Option<L<Visibility>> {
         let L(t0, _) = self.eat_ident("pub")?;
         let vis = self
-            .eat_delim(Delimiter::Parens, |t1, mut this| {
+            .check_vis_restriction()
+            .then(|| self.eat_delim(Delimiter::Parens, |t1, mut this| {
                 let (t2, in_, path) = if let Some(L(t2, _)) = this.eat_ident("in") {
-                    let L(t2_5, path) = this.parse_path();
+                    let L(t2_5, path) = this.parse_path(PathStyle::Mod);
                     (t2, Some((Token![in], TriviaN::new(t2_5))), path)
                 } else {
                     let L(t2, ident) = this.parse_ident();
@@ -426,13 +514,36 @@ impl<'src> Parser<'src> {
                 Visibility::Restricted {
                     pub_: Token![pub],
                     t1,
-                    parens: Parens(VisRestricted { t2, in_, path, t3 }),
+                    parens: Box::new(Parens(VisRestricted { t2, in_, path, t3 })),
                 }
-            })
+            }))
+            .flatten()
             .unwrap_or(Visibility::Public { pub_: Token![pub] });
 
         Some(t0 << vis)
     }
+    /// Parses comma separated values until the end of the current delimited group.
+    pub fn parse_comma_sep_to_eof<T>(
+        &mut self,
+        mut f: impl FnMut(&mut Self) -> L<T>,
+    ) -> L<SeparatedList<T, Token![,]>> {
+        let mut builder = SeparatedListBuilder::new();
+        let tlast = loop {
+            if let Some(tlast) = self.eat_eof() {
+                break tlast;
+            }
+            let L(t, x) = f(self);
+            builder.push_value(t, x);
+            if let Some(tlast) = self.eat_eof() {
+                break tlast;
+            }
+            let t = self.eat_punct(Punct::Comma).unwrap();
+            builder.push_sep(t, Token![,]);
+        };
+        let L(t0, mut list) = builder.build();
+        list.push_trivia(tlast);
+        t0 << list
+    }
     pub fn parse_module(&mut self) -> Module {
         let (t1, attrs) = self.parse_attrs(AttrKind::Inner).unwrap_or_default();
         let mut module = Module {
@@ -461,9 +572,32 @@ impl<'src> Parser<'src> {
 }
 
 pub fn parse(s: &str) -> File {
-    let mut p = Parser::new(s);
+    parse_with_config(s, ParserConfig::default())
+}
+
+pub fn parse_with_config(s: &str, config: ParserConfig) -> File {
+    let (shebang, s) = match strip_shebang(s) {
+        Some(len) => (Some(Shebang(SmolStr::new(&s[..len]))), &s[len..]),
+        None => (None, s),
+    };
+    let allowed = || if config.nightly { FrontmatterAllowed::Yes } else { FrontmatterAllowed::No };
+    let mut lexer = tokenize(s, allowed());
+    let mut frontmatter = None;
+    // lexers cannot be rewound, so look for the frontmatter with a second one
+    let mut peek = tokenize(s, allowed());
+    if let (t0, TokenKind::Frontmatter { has_invalid_preceding_whitespace, invalid_infostring }, text) =
+        peek.next()
+    {
+        assert!(
+            !has_invalid_preceding_whitespace && !invalid_infostring,
+            "invalid frontmatter"
+        );
+        frontmatter = Some(t0 << Frontmatter(text));
+        lexer = peek;
+    }
+    let mut p = Parser::create(Gluer::new(lexer), config);
     let module = p.parse_module();
-    File { module }
+    File { shebang, frontmatter, module }
 }
 
 #[cfg(test)]
@@ -471,6 +605,7 @@ pub fn parse_trivia(s: &str) -> Trivia {
     Parser::new(s).eat_eof().unwrap()
 }
 
+/// Parses `s` as the input of a macro, which cannot have a frontmatter.
 pub fn parse_to_tokenstream(s: &str) -> TokenStream {
-    Gluer::new(crate::lex::tokenize(s)).collect()
+    Gluer::new(tokenize(s, FrontmatterAllowed::No)).collect()
 }
@@ -1,12 +1,241 @@
 use crate::prelude::*;
+use crate::parse::attr::AttrKind;
+
+/// Whether `tt` can start the end of a range pattern, so that `0..` followed by it is not
+/// a half-open range.
+fn can_begin_range_end(tt: &TokenTree) -> bool {
+    match tt {
+        TokenTree::Literal(_) => true,
+        TokenTree::Ident(_) => !tt.is_ident("if"),
+        TokenTree::Punct(p) => matches!(p, Punct::Minus | Punct::ColonColon | Punct::Lt),
+        _ => false,
+    }
+}
 
 impl<'src> super::Parser<'src> {
+    fn check_const_block(&self) -> bool {
+        self.config.nightly
+            && self.check_ident("const")
+            && self.peek2(|tt| tt.is_delim(Delimiter::Braces))
+    }
+
+    /// Parses a literal, a const block or a path, as found at either end of a range pattern.
+    fn parse_pat_range_bound(&mut self) -> L<Pat> {
+        if let Some(t0) = self.eat_punct(Punct::Minus) {
+            let L(t1, lit) = self.eat_literal().expect("expected a literal after `-`");
+            t0 << Pat::Lit(PatLit { neg: Some((Token![-], t1)), lit })
+        } else if let Some(L(t0, lit)) = self.eat_literal() {
+            t0 << Pat::Lit(PatLit { neg: None, lit })
+        } else if self.check_const_block() {
+            let t0 = self.eat_kw("const").unwrap();
+            let L(t1, block) = self.parse_block();
+            t0 << Pat::ConstBlock(ConstBlock { token: Token![const], t1, block })
+        } else {
+            self.parse_qpath(PathStyle::Expr).map(Pat::Path)
+        }
+    }
+
+    /// Turns `start` into a range pattern if it is followed by `..` or `..=`.
+    fn parse_pat_range_rest(&mut self, L(t0, start): L<Pat>) -> L<Pat> {
+        let Some(L(t1, limits)) = self.parse_range_limits() else {
+            return t0 << start;
+        };
+        let end = self.peek(can_begin_range_end).then(|| self.parse_pat_range_bound().map(Box::new));
+        t0 << Pat::Range(PatRange { start: Some((Box::new(start), t1)), limits, end })
+    }
+
+    fn parse_pat_ident(&mut self) -> L<PatIdent> {
+        let tref = self.eat_kw("ref");
+        let tmut = self.eat_kw("mut");
+        let L(mut tnext, ident) = self.parse_ident();
+        // the trivia before each keyword trails the keyword preceding it
+        let mut_ = tmut.map(|t| (Token![mut], TriviaN::new(std::mem::replace(&mut tnext, t))));
+        let by_ref = tref.map(|t| (Token![ref], TriviaN::new(std::mem::replace(&mut tnext, t))));
+        let sub = self.eat_punct(Punct::At).map(|t1| {
+            let L(t2, pat) = self.parse_pat();
+            (t1, Token![@], t2, Box::new(pat))
+        });
+        tnext << PatIdent { by_ref, mut_, ident, sub }
+    }
+
+    /// Parses a pattern where a guard is allowed on nightly, like the elements of a tuple.
+    fn parse_pat_allow_top_guard(&mut self) -> L<Pat> {
+        let L(t0, pat) = self.parse_multi_pat_with_leading_vert();
+        let Some(t1) = self.config.nightly.then(|| self.eat_kw("if")).flatten() else {
+            return t0 << pat;
+        };
+        let L(t2, cond) = self.parse_expr();
+        t0 << Pat::Guard(PatGuard { pat: Box::new(pat), t1, if_: Token![if], t2, cond: Box::new(cond) })
+    }
+
+    fn parse_pat_struct_field(&mut self) -> L<PatStructField> {
+        let attrs = self.parse_attrs(AttrKind::Outer);
+        let L(t1, (member, pat)) = if self
+            .peek(|tt| matches!(tt, TokenTree::Ident(_) | TokenTree::Literal(_)))
+            && self.peek2(|tt| tt.is_punct(Punct::Colon))
+        {
+            let L(t, member) = match self.eat_literal() {
+                Some(L(t, lit)) => t << Member::Unnamed(lit),
+                None => self.parse_ident().map(Member::Named),
+            };
+            let tcolon = self.eat_punct(Punct::Colon).unwrap();
+            let L(tpat, pat) = self.parse_pat_allow_top_guard();
+            t << (Some((member, tcolon, Token![:], tpat)), pat)
+        } else {
+            self.parse_pat_allow_top_guard().map(|pat| (None, pat))
+        };
+        let (t0, attrs) = match attrs {
+            Some((t0, mut attrs)) => {
+                attrs.push_trivia(t1);
+                (t0, attrs)
+            }
+            None => (t1, List::default()),
+        };
+        t0 << PatStructField { attrs, member, pat }
+    }
+
+    fn parse_pat_struct_fields(&mut self) -> L<Braces<PatStructFields>> {
+        self.eat_delim(Delimiter::Braces, |t0, mut this| {
+            let mut builder = SeparatedListBuilder::new();
+            let mut dot2 = None;
+            let tlast = loop {
+                if let Some(tlast) = this.eat_eof() {
+                    break tlast;
+                }
+                if let Some(t) = this.eat_punct(Punct::DotDot) {
+                    dot2 = Some(t << Token![..]);
+                    break this.eat_eof().expect("`..` must come last in a struct pattern");
+                }
+                let L(t, field) = this.parse_pat_struct_field();
+                builder.push_value(t, field);
+                if let Some(tlast) = this.eat_eof() {
+                    break tlast;
+                }
+                let t = this.eat_punct(Punct::Comma).unwrap();
+                builder.push_sep(t, Token![,]);
+            };
+            let L(t1, fields) = builder.build();
+            t0 << Braces(PatStructFields { t1, fields, dot2, tlast })
+        })
+        .expect("expected struct pattern fields")
+    }
+
+    fn parse_pat_tuple_fields(&mut self) -> L<Parens<PatTuple>> {
+        self.eat_delim(Delimiter::Parens, |t0, mut this| {
+            let L(t1, pats) = this.parse_comma_sep_to_eof(Self::parse_pat_allow_top_guard);
+            t0 << Parens(PatTuple { t1, pats })
+        })
+        .expect("expected tuple pattern fields")
+    }
+
     pub fn parse_pat(&mut self) -> L<Pat> {
-        let L(t0, ident) = self.parse_ident();
-        t0 << Pat::Ident(ident)
+        if let Some(pat) = self.eat_delim(Delimiter::Parens, |t0, mut this| {
+            if let Some(tlast) = this.eat_eof() {
+                let mut pats = SeparatedList::new();
+                pats.push_trivia(tlast);
+                return t0 << Pat::Tuple(Parens(PatTuple { t1: Trivia::default(), pats }));
+            }
+            let L(t1, first) = this.parse_pat_allow_top_guard();
+            // `(..)` is a tuple with a rest pattern, not a parenthesized one
+            if !matches!(first, Pat::Rest(_))
+                && let Some(t2) = this.eat_eof()
+            {
+                return t0 << Pat::Paren(Parens(PatParen { t1, pat: Box::new(first), t2 }));
+            }
+            let mut pats = SeparatedList::new_single(first);
+            let tlast = loop {
+                if let Some(tlast) = this.eat_eof() {
+                    break tlast;
+                }
+                let t = this.eat_punct(Punct::Comma).unwrap();
+                pats.push_sep(t, Token![,]);
+                if let Some(tlast) = this.eat_eof() {
+                    break tlast;
+                }
+                let L(t, pat) = this.parse_pat_allow_top_guard();
+                pats.push_value(t, pat);
+            };
+            pats.push_trivia(tlast);
+            t0 << Pat::Tuple(Parens(PatTuple { t1, pats }))
+        }) {
+            pat
+        } else if let Some(pat) = self.eat_delim(Delimiter::Brackets, |t0, mut this| {
+            let L(t1, pats) = this.parse_comma_sep_to_eof(Self::parse_pat_allow_top_guard);
+            t0 << Pat::Slice(Brackets(PatSlice { t1, pats }))
+        }) {
+            pat
+        } else if let Some(t0) = self.eat_punct_split(Punct::And) {
+            let mut_ = self.eat_kw("mut").map(|t| (t, Token![mut]));
+            let L(t1, pat) = self.parse_pat();
+            t0 << Pat::Ref(PatRef { and: Token![&], mut_, t1, pat: Box::new(pat) })
+        } else if self.config.nightly && let Some(t0) = self.eat_kw("box") {
+            let L(t1, pat) = self.parse_pat();
+            t0 << Pat::Box(PatBox { kw: Token![box], t1, pat: Box::new(pat) })
+        } else if let Some(t0) = self.eat_kw("_") {
+            t0 << Pat::Wild(Token![_])
+        } else if self.config.nightly
+            && let Some(t0) = self.eat_punct(Punct::Bang)
+        {
+            t0 << Pat::Never(Token![!])
+        } else if self.check_punct(Punct::DotDotEq)
+            || (self.check_punct(Punct::DotDot) && self.peek2(can_begin_range_end))
+        {
+            let L(t0, limits) = self.parse_range_limits().unwrap();
+            let end = self.parse_pat_range_bound().map(Box::new);
+            t0 << Pat::Range(PatRange { start: None, limits, end: Some(end) })
+        } else if let Some(t0) = self.eat_punct(Punct::DotDot) {
+            t0 << Pat::Rest(Token![..])
+        } else if self.check_punct(Punct::Minus)
+            || self.peek(|tt| matches!(tt, TokenTree::Literal(_)))
+            || self.check_const_block()
+        {
+            let bound = self.parse_pat_range_bound();
+            self.parse_pat_range_rest(bound)
+        } else if self.check_ident("ref")
+            || self.check_ident("mut")
+            || (self.peek(|tt| matches!(tt, TokenTree::Ident(_)))
+                && !self.peek2(|tt| {
+                    tt.is_punct(Punct::ColonColon)
+                        || tt.is_punct(Punct::Bang)
+                        || tt.is_punct(Punct::DotDot)
+                        || tt.is_punct(Punct::DotDotEq)
+                        || tt.is_delim(Delimiter::Parens)
+                        || tt.is_delim(Delimiter::Braces)
+                }))
+        {
+            self.parse_pat_ident().map(Pat::Ident)
+        } else {
+            let L(t0, qpath) = self.parse_qpath(PathStyle::Expr);
+            if qpath.qself.is_none() && self.check_punct(Punct::Bang) && qpath.path.has_no_args() {
+                t0 << Pat::MacCall(self.parse_rest_of_macro_call(qpath.path))
+            } else if self.peek(|tt| tt.is_delim(Delimiter::Parens)) {
+                let L(t1, fields) = self.parse_pat_tuple_fields();
+                t0 << Pat::TupleStruct(PatTupleStruct { qpath, t1, fields })
+            } else if self.peek(|tt| tt.is_delim(Delimiter::Braces)) {
+                let L(t1, fields) = self.parse_pat_struct_fields();
+                t0 << Pat::Struct(PatStruct { qpath, t1, fields })
+            } else {
+                self.parse_pat_range_rest(t0 << Pat::Path(qpath))
+            }
+        }
     }
+
+    /// Parses an or-pattern with an optional leading `|`, a single pattern is returned as is.
     pub fn parse_multi_pat_with_leading_vert(&mut self) -> L<Pat> {
-        // TODO
-        self.parse_pat()
+        let vert = self.eat_punct(Punct::Or);
+        let L(t1, first) = self.parse_pat();
+        if vert.is_none() && !self.check_punct(Punct::Or) {
+            return t1 << first;
+        }
+        let mut pats = SeparatedList::new_single(first);
+        while let Some(t) = self.eat_punct(Punct::Or) {
+            pats.push_sep(t, Token![|]);
+            let L(t, pat) = self.parse_pat();
+            pats.push_value(t, pat);
+        }
+        match vert {
+            Some(t0) => t0 << Pat::Or(PatOr { vert: Some((Token![|], t1)), pats }),
+            None => t1 << Pat::Or(PatOr { vert: None, pats }),
+        }
     }
 }
@@ -0,0 +1,158 @@
+use crate::prelude::*;
+
+/// Where a path appears, which decides how generic arguments are written.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PathStyle {
+    /// `Vec::<u8>::new`, generic args need a turbofish.
+    Expr,
+    /// `Vec<u8>`, `Fn(u8) -> u8` and `f(..)`.
+    Type,
+    /// `a::b` in attributes, visibilities and macro calls, no generic args.
+    Mod,
+}
+
+impl<'src> super::Parser<'src> {
+    /// Whether the tokens ahead are `Ident =` or `Ident: Bound`, possibly with generic
+    /// args after the ident, like the `Item<'a> = u8` of a generic associated type.
+    fn check_assoc_constraint(&self) -> bool {
+        if !self.peek(|tt| matches!(tt, TokenTree::Ident(_))) {
+            return false;
+        }
+        let mut this = self.snapshot();
+        this.bump();
+        if this.check_punct_split(Punct::Lt) {
+            // skip over the args by counting angle brackets, `>=` and `>>=` may close them
+            // and leave the `=` behind
+            let mut depth = 0isize;
+            loop {
+                let L(_, tt) = this.bump();
+                match tt {
+                    TokenTree::Punct(Punct::Lt | Punct::LThinArrow) => depth += 1,
+                    TokenTree::Punct(Punct::Gt) => depth -= 1,
+                    TokenTree::Punct(Punct::GtEq) => return depth == 1,
+                    TokenTree::Punct(Punct::GtGtEq) => return depth == 2,
+                    TokenTree::Eof => return false,
+                    _ => {}
+                }
+                if depth <= 0 {
+                    break;
+                }
+            }
+        }
+        this.check_punct(Punct::Eq) || this.check_punct(Punct::Colon)
+    }
+
+    fn check_const_arg(&self) -> bool {
+        self.peek(|tt| {
+            matches!(tt, TokenTree::Literal(_))
+                || tt.is_delim(Delimiter::Braces)
+                || tt.is_punct(Punct::Minus)
+        }) || (self.config.nightly
+            && self.check_ident("const")
+            && self.peek2(|tt| tt.is_delim(Delimiter::Braces)))
+    }
+
+    pub fn parse_generic_arg(&mut self) -> L<GenericArg> {
+        if self.peek(|tt| matches!(tt, TokenTree::Lifetime(_))) {
+            self.bump().map(|tt| GenericArg::Lifetime(tt.into_lifetime().unwrap()))
+        } else if self.check_const_arg() {
+            self.parse_const_arg().map(GenericArg::Const)
+        } else if self.check_assoc_constraint() {
+            let L(t0, ident) = self.parse_ident();
+            let args = self
+                .check_punct_split(Punct::Lt)
+                .then(|| self.parse_angle_bracketed_args());
+            let arg = if let Some(bounds) = self.parse_type_bounds() {
+                GenericArg::Bound(AssocBound { ident, args, bounds })
+            } else {
+                let t1 = self.eat_punct(Punct::Eq).unwrap();
+                if self.check_const_arg() {
+                    let L(t2, expr) = self.parse_const_arg();
+                    GenericArg::AssocConst(AssocConst { ident, args, t1, eq: Token![=], t2, expr })
+                } else {
+                    let L(t2, ty) = self.parse_ty();
+                    GenericArg::AssocType(AssocType { ident, args, t1, eq: Token![=], t2, ty })
+                }
+            };
+            t0 << arg
+        } else {
+            self.parse_ty().map(GenericArg::Type)
+        }
+    }
+
+    /// Parses `<T, U>` or `::<T, U>`.
+    pub fn parse_angle_bracketed_args(&mut self) -> L<PathSegmentArgs> {
+        let (t0, colon2) = if let Some(t0) = self.eat_punct(Punct::ColonColon) {
+            let t1 = self.eat_punct_split(Punct::Lt).unwrap();
+            (t0, Some((Token![::], t1)))
+        } else {
+            (self.eat_punct_split(Punct::Lt).unwrap(), None)
+        };
+        let mut builder = SeparatedListBuilder::new();
+        let tlast = loop {
+            if let Some(tlast) = self.eat_punct_split(Punct::Gt) {
+                break tlast;
+            }
+            let L(t, arg) = self.parse_generic_arg();
+            builder.push_value(t, arg);
+            if let Some(tlast) = self.eat_punct_split(Punct::Gt) {
+                break tlast;
+            }
+            let t = self.eat_punct(Punct::Comma).unwrap();
+            builder.push_sep(t, Token![,]);
+        };
+        let L(t1, mut args) = builder.build();
+        args.push_trivia(tlast);
+        t0 << PathSegmentArgs::AngleBracketed {
+            colon2,
+            lt: Token![<],
+            t1,
+            args,
+            gt: Token![>],
+        }
+    }
+
+    /// Parses `(A, B) -> C` or `(..)`.
+    fn parse_parenthesized_args(&mut self) -> L<PathSegmentArgs> {
+        let mut args = self
+            .eat_delim(Delimiter::Parens, |t0, mut this| {
+                if this.check_punct(Punct::DotDot) && this.peek2(|tt| matches!(tt, TokenTree::Eof)) {
+                    let t1 = this.eat_punct(Punct::DotDot).unwrap();
+                    let t2 = this.eat_eof().unwrap();
+                    return t0
+                        << PathSegmentArgs::ReturnType(Parens(ReturnTypeNotation {
+                            t1,
+                            dots: Token![..],
+                            t2,
+                        }));
+                }
+                let L(t1, tys) = this.parse_comma_sep_to_eof(Self::parse_ty);
+                t0 << PathSegmentArgs::Parenthesized { inputs: Parens(TyTuple { t1, tys }), ret: None }
+            })
+            .unwrap();
+        if let PathSegmentArgs::Parenthesized { inputs: _, ret } = &mut args.1 {
+            *ret = self.parse_fn_ret().map(|(t, ret)| (t, Box::new(ret)));
+        }
+        args
+    }
+
+    fn parse_path_segment_args(&mut self, style: PathStyle) -> Option<L<PathSegmentArgs>> {
+        let turbofish = self.check_punct(Punct::ColonColon) && self.peek2(|tt| tt.is_punct(Punct::Lt));
+        match style {
+            PathStyle::Expr | PathStyle::Type if turbofish => Some(self.parse_angle_bracketed_args()),
+            PathStyle::Type if self.check_punct_split(Punct::Lt) => {
+                Some(self.parse_angle_bracketed_args())
+            }
+            PathStyle::Type if self.peek(|tt| tt.is_delim(Delimiter::Parens)) => {
+                Some(self.parse_parenthesized_args())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn parse_path_segment(&mut self, style: PathStyle) -> L<PathSegment> {
+        let L(t0, ident) = self.parse_ident();
+        let args = self.parse_path_segment_args(style);
+        t0 << PathSegment { ident, args }
+    }
+}
@@ -1,12 +1,116 @@
 use crate::prelude::*;
 use crate::parse::attr::AttrKind;
 
+/// Whether the expression ends with a `}`, which is not allowed before the `else` of a `let`.
+fn expr_trailing_brace(kind: &ExprKind) -> bool {
+    match kind {
+        ExprKind::Block(_)
+        | ExprKind::AsyncBlock(_)
+        | ExprKind::TryBlock(_)
+        | ExprKind::Unsafe(_)
+        | ExprKind::Const(_)
+        | ExprKind::If(_)
+        | ExprKind::While(_)
+        | ExprKind::For(_)
+        | ExprKind::Loop(_)
+        | ExprKind::Match(_)
+        | ExprKind::PostfixMatch(_)
+        | ExprKind::Struct(_) => true,
+        ExprKind::Macro(mac) => mac.inner.delimiter() == Delimiter::Braces,
+        ExprKind::Closure(closure) => expr_trailing_brace(&closure.body.kind),
+        ExprKind::Binary(ExprBinary { rhs: e, .. })
+        | ExprKind::Assign(ExprAssign { rhs: e, .. })
+        | ExprKind::AssignOp(ExprAssignOp { rhs: e, .. })
+        | ExprKind::Unary(ExprUnary { expr: e, .. })
+        | ExprKind::Ref(ExprRef { expr: e, .. })
+        | ExprKind::Become(Become { expr: e, .. })
+        | ExprKind::Let(ExprLet { expr: e, .. })
+        | ExprKind::Range(ExprRange { end: Some(L(_, e)), .. })
+        | ExprKind::Break(Break { expr: Some(L(_, e)), .. })
+        | ExprKind::Return(Return { expr: Some(L(_, e)), .. })
+        | ExprKind::Yield(Yield { expr: Some(L(_, e)), .. })
+        | ExprKind::Yeet(Yeet { expr: Some(L(_, e)), .. }) => expr_trailing_brace(&e.kind),
+        _ => false,
+    }
+}
+
 impl<'src> Parser<'src> {
+    fn parse_local(&mut self) -> Option<L<Local>> {
+        let t0 = self.eat_kw("let")?;
+        let L(t1, pat) = self.parse_multi_pat_with_leading_vert();
+        let ty = self.eat_punct(Punct::Colon).map(|t1| {
+            let L(t2, ty) = self.parse_ty();
+            (t1, Token![:], t2, ty)
+        });
+        let init = self.eat_punct(Punct::Eq).map(|t2| {
+            let L(t3, expr) = self.parse_expr().map(Box::new);
+            let else_ = self.eat_kw("else").map(|tbeforeelse| {
+                if expr_trailing_brace(&expr.kind) {
+                    panic!("right curly brace `}}` before `else` in a `let...else` statement not allowed");
+                }
+                if let ExprKind::Binary(ExprBinary { op: BinOp::And(_) | BinOp::Or(_), .. }) = expr.kind {
+                    panic!("a lazy boolean expression cannot be directly assigned in `let...else`");
+                }
+                let L(tafterelse, block) = self.parse_block();
+                (tbeforeelse, Token![else], tafterelse, block)
+            });
+            LocalInit {
+                t2,
+                eq: Token![=],
+                t3,
+                expr,
+                else_,
+            }
+        });
+        let t4 = self.eat_punct(Punct::Semi).unwrap();
+        Some(
+            t0 << Local {
+                token: Token![let],
+                t1,
+                pat,
+                ty,
+                init,
+                t4,
+                semi: Token![;],
+            },
+        )
+    }
+
     pub fn parse_stmt(&mut self) -> (Trivia, Stmt) {
-        let (t0, mut attrs) = self.parse_attrs(AttrKind::Outer).unwrap_or_default();
+        let attrs = self.parse_attrs(AttrKind::Outer);
+        if self.check_item_start() {
+            let (t0, item) = self.parse_items_without_attrs(attrs);
+            let kind = StmtKind::Item(item);
+            return (t0, Stmt { attrs: List::default(), kind });
+        }
+        let (t0, mut attrs) = attrs.unwrap_or_default();
         let kind = if let Some(trivia) = self.eat_punct(Punct::Semi) {
             attrs.push_trivia(trivia);
             StmtKind::Empty(Token![;])
+        } else if let Some(L(t1, local)) = self.parse_local() {
+            attrs.push_trivia(t1);
+            StmtKind::Local(local)
+        } else if self.check_macro_call() {
+            let L(t1, path) = self.parse_path(PathStyle::Mod);
+            attrs.push_trivia(t1);
+            let mac = self.parse_rest_of_macro_call(path);
+            let braces = mac.inner.delimiter() == Delimiter::Braces;
+            if braces && !self.check_punct(Punct::Dot) && !self.check_punct(Punct::Question) {
+                let semi = self.eat_punct(Punct::Semi).map(|t| (t, Token![;]));
+                StmtKind::MacCall(mac, semi)
+            } else if let Some(t2) = self.eat_punct(Punct::Semi) {
+                StmtKind::MacCall(mac, Some((t2, Token![;])))
+            } else if self.check_eof() {
+                StmtKind::MacCall(mac, None)
+            } else {
+                // the macro call is only the start of an expression, `foo!().bar();`
+                let expr = self.parse_rest_of_expr(ExprKind::Macro(Box::new(mac)));
+                if let Some(t2) = self.eat_punct(Punct::Semi) {
+                    StmtKind::Semi(expr, t2, Token![;])
+                } else {
+                    StmtKind::Expr(expr)
+                }
+            }
         } else {
             let L(t1, expr) = self.parse_expr_with_earlier_boundary_rule();
             attrs.push_trivia(t1);
@@ -22,7 +126,7 @@ impl<'src> Parser<'src> {
 
     pub fn parse_block(&mut self) -> L<Block> {
         self.eat_delim(Delimiter::Braces, |t0, mut this | {
-            // TODO inner attributes?
+            let inner_attrs = this.parse_attrs(AttrKind::Inner);
             let mut stmts = List::default();
             let mut tstart = None;
             let tend = loop {
@@ -40,7 +144,14 @@ impl<'src> Parser<'src> {
             };
             let tstart = tstart.unwrap_or_default();
             stmts.push_trivia(tend);
-            let b = BlockInner { t0: tstart, stmts };
+            let (tstart, attrs) = match inner_attrs {
+                Some((tattrs, mut attrs)) => {
+                    attrs.push_trivia(tstart);
+                    (tattrs, attrs)
+                }
+                None => (tstart, List::default()),
+            };
+            let b = BlockInner { t0: tstart, attrs, stmts };
             t0 << Braces(b)
         }).unwrap()
     }
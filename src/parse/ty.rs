@@ -1,6 +1,70 @@
 use crate::prelude::*;
+use crate::parse::attr::AttrKind;
 
 impl<'src> super::Parser<'src> {
+    fn parse_ptr_mutability(&mut self) -> (Trivia, PtrMutability) {
+        if let Some(t) = self.eat_kw("const") {
+            (t, PtrMutability::Const(Token![const]))
+        } else {
+            let t = self.eat_kw("mut").expect("expected `const` or `mut`");
+            (t, PtrMutability::Mut(Token![mut]))
+        }
+    }
+
+    fn check_bare_fn(&self) -> bool {
+        (self.check_ident("for") && self.peek2(|tt| tt.is_punct(Punct::Lt)))
+            || self.check_ident("fn")
+            || self.check_ident("extern")
+            || (self.check_ident("unsafe") && self.peek2(|tt| tt.is_ident("extern") || tt.is_ident("fn")))
+    }
+
+    fn parse_bare_fn_param(&mut self) -> L<BareFnParam> {
+        let attrs = self.parse_attrs(AttrKind::Outer);
+        let L(t1, kind) = if let Some(t) = self.eat_punct(Punct::DotDotDot) {
+            t << BareFnParamKind::Variadic(Token![...])
+        } else if self.peek(|tt| matches!(tt, TokenTree::Ident(_)))
+            && self.peek2(|tt| tt.is_punct(Punct::Colon))
+        {
+            let L(t, name) = self.parse_ident();
+            let tcolon = self.eat_punct(Punct::Colon).unwrap();
+            let L(tty, ty) = self.parse_ty();
+            t << BareFnParamKind::Typed { name: Some((name, tcolon, Token![:], tty)), ty: Box::new(ty) }
+        } else {
+            self.parse_ty().map(|ty| BareFnParamKind::Typed { name: None, ty: Box::new(ty) })
+        };
+        let (t0, attrs) = match attrs {
+            Some((t0, mut attrs)) => {
+                attrs.push_trivia(t1);
+                (t0, attrs)
+            }
+            None => (t1, List::default()),
+        };
+        t0 << BareFnParam { attrs, kind }
+    }
+
+    fn parse_ty_bare_fn(&mut self) -> L<TyBareFn> {
+        let bound_lifetimes = self.parse_bound_lifetimes();
+        let safety = self.eat_kw("unsafe").map(|t| (t, Safety::Unsafe(Token![unsafe])));
+        let extern_ = self.eat_kw("extern").map(|t| {
+            let abi = self.eat_literal().map(|L(t, abi)| (t, abi));
+            (t, Extern { kw: Token![extern], abi })
+        });
+        let mut tnext = self.eat_kw("fn").expect("expected `fn`");
+        // the trivia before each keyword trails the keyword preceding it
+        let extern_ = extern_.map(|(t, e)| (e, std::mem::replace(&mut tnext, t)));
+        let safety = safety.map(|(t, s)| (s, std::mem::replace(&mut tnext, t)));
+        let bound_lifetimes =
+            bound_lifetimes.map(|L(t, bl)| (bl, std::mem::replace(&mut tnext, t)));
+        let L(t1, params) = self
+            .eat_delim(Delimiter::Parens, |t1, mut this| {
+                let L(t, params) = this.parse_comma_sep_to_eof(Self::parse_bare_fn_param);
+                t1 << Parens(BareFnParams { t1: t, params })
+            })
+            .expect("expected parameters");
+        let ret = self.parse_fn_ret();
+        tnext << TyBareFn { bound_lifetimes, safety, extern_, kw: Token![fn], t1, params, ret }
+    }
+
     pub fn parse_ty(&mut self) -> L<Ty> {
         if let Some(ty) = self.eat_delim(Delimiter::Brackets, |t0, mut this| {
             let L(t1, ty) = this.parse_ty();
@@ -16,8 +80,87 @@ impl<'src> super::Parser<'src> {
             t0 << kind
         }) {
             ty
+        } else if let Some(ty) = self.eat_delim(Delimiter::Parens, |t0, mut this| {
+            if let Some(tlast) = this.eat_eof() {
+                let mut tys = SeparatedList::new();
+                tys.push_trivia(tlast);
+                return t0 << Ty::Tuple(Parens(TyTuple { t1: Trivia::default(), tys }));
+            }
+            let L(t1, first) = this.parse_ty();
+            if let Some(t2) = this.eat_eof() {
+                return t0 << Ty::Paren(Parens(TyParen { t1, ty: Box::new(first), t2 }));
+            }
+            let mut tys = SeparatedList::new_single(first);
+            let tlast = loop {
+                let t = this.eat_punct(Punct::Comma).unwrap();
+                tys.push_sep(t, Token![,]);
+                if let Some(tlast) = this.eat_eof() {
+                    break tlast;
+                }
+                let L(t, ty) = this.parse_ty();
+                tys.push_value(t, ty);
+                if let Some(tlast) = this.eat_eof() {
+                    break tlast;
+                }
+            };
+            tys.push_trivia(tlast);
+            t0 << Ty::Tuple(Parens(TyTuple { t1, tys }))
+        }) {
+            ty
+        } else if let Some(t0) = self.eat_punct_split(Punct::And) {
+            let lifetime = self.eat_lifetime().map(|L(t, lifetime)| (t, lifetime));
+            let kind = if let Some(t) = self.eat_kw("mut") {
+                TyRefKind::Mut(t, Token![mut])
+            } else if self.config.nightly
+                && self.check_ident("pin")
+                && self.peek2(|tt| tt.is_ident("mut") || tt.is_ident("const"))
+            {
+                let t = self.eat_kw("pin").unwrap();
+                let (t2, mutability) = self.parse_ptr_mutability();
+                TyRefKind::Pin(t, Token![pin], t2, mutability)
+            } else {
+                TyRefKind::Ref
+            };
+            let L(t1, ty) = self.parse_ty();
+            t0 << Ty::Ref(TyRef { and: Token![&], lifetime, kind, t1, ty: Box::new(ty) })
+        } else if let Some(t0) = self.eat_punct(Punct::Star) {
+            let (t1, mutability) = self.parse_ptr_mutability();
+            let L(t2, ty) = self.parse_ty();
+            t0 << Ty::Ptr(TyPtr { star: Token![*], t1, mutability, t2, ty: Box::new(ty) })
+        } else if self.config.nightly && let Some(t0) = self.eat_punct(Punct::Bang) {
+            t0 << Ty::Never(Token![!])
+        } else if let Some(t0) = self.eat_kw("_") {
+            t0 << Ty::Infer(Token![_])
+        } else if self.check_ident("dyn") && !self.peek2(|tt| tt.is_punct(Punct::ColonColon)) {
+            let t0 = self.eat_kw("dyn").unwrap();
+            let L(t1, bounds) = self.parse_type_param_bounds();
+            t0 << Ty::TraitObject(TyTraitObject { kw: Token![dyn], t1, bounds })
+        } else if let Some(t0) = self.eat_kw("impl") {
+            let L(t1, bounds) = self.parse_type_param_bounds();
+            t0 << Ty::ImplTrait(TyImplTrait { kw: Token![impl], t1, bounds })
+        } else if self.check_bare_fn() {
+            self.parse_ty_bare_fn().map(|f| Ty::BareFn(Box::new(f)))
+        } else if self.config.nightly
+            && self.check_ident("unsafe")
+            && self.peek2(|tt| tt.is_punct(Punct::Lt))
+        {
+            let t0 = self.eat_kw("unsafe").unwrap();
+            let L(t1, generics) = self.parse_generics().unwrap();
+            let L(t2, ty) = self.parse_ty();
+            t0 << Ty::UnsafeBinder(TyUnsafeBinder {
+                kw: Token![unsafe],
+                t1,
+                generics,
+                t2,
+                ty: Box::new(ty),
+            })
         } else {
-            self.parse_qpath().map(Ty::Path)
+            let L(t0, qpath) = self.parse_qpath(PathStyle::Type);
+            if qpath.qself.is_none() && self.check_punct(Punct::Bang) && qpath.path.has_no_args() {
+                t0 << Ty::MacCall(Box::new(self.parse_rest_of_macro_call(qpath.path)))
+            } else {
+                t0 << Ty::Path(Box::new(qpath))
+            }
         }
     }
 }
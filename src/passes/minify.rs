@@ -1,8 +1,17 @@
-use crate::ast::{Trivia, TriviaN};
-use crate::passes::Pass;
+use smol_str::SmolStr;
+
+use crate::ast::{File, Trivia, TriviaN, Trivium};
+use crate::parse::L;
+use crate::passes::{Pass, Walk};
 
 pub struct Minify;
 
+fn newline() -> Trivia {
+    let mut t = Trivia::default();
+    t.push(Trivium::Whitespace(SmolStr::new_inline("\n")));
+    t
+}
+
 impl Pass for Minify {
     fn visit_trivia(&mut self, t: &mut Trivia) {
         *t = Trivia::default();
@@ -10,4 +19,15 @@ impl Pass for Minify {
     fn visit_trivia_n(&mut self, t: &mut TriviaN) {
         *t = TriviaN::single_space();
     }
+    fn visit_file(&mut self, f: &mut File) {
+        f.walk(self);
+        // the shebang and the frontmatter must end their lines
+        let File { shebang, frontmatter, module } = f;
+        if let (Some(_), Some(L(t, _))) = (shebang.as_ref(), frontmatter.as_mut()) {
+            *t = newline();
+        }
+        if shebang.is_some() || frontmatter.is_some() {
+            module.t1 = newline();
+        }
+    }
 }
@@ -45,6 +45,9 @@ pub trait Pass {
         visit_trivia_n(TriviaN);
         visit_ident(Ident);
         visit_literal(Literal);
+        visit_guarded_str(GuardedStr);
+        visit_shebang(Shebang);
+        visit_frontmatter(Frontmatter);
     }
 
     visit_default_walk! {
@@ -66,10 +69,27 @@ pub trait Pass {
         visit_path(Path);
         visit_path_segment(PathSegment);
         visit_path_segment_args(PathSegmentArgs);
+        visit_assoc_type(AssocType);
+        visit_assoc_const(AssocConst);
+        visit_assoc_bound(AssocBound);
+        visit_return_type_notation(ReturnTypeNotation);
         visit_generic_arg(GenericArg);
         visit_ty(Ty);
         visit_ty_slice(TySlice);
         visit_ty_array(TyArray);
+        visit_ty_ref_kind(TyRefKind);
+        visit_ty_ref(TyRef);
+        visit_ptr_mutability(PtrMutability);
+        visit_ty_ptr(TyPtr);
+        visit_ty_tuple(TyTuple);
+        visit_ty_paren(TyParen);
+        visit_bare_fn_param_kind(BareFnParamKind);
+        visit_bare_fn_param(BareFnParam);
+        visit_bare_fn_params(BareFnParams);
+        visit_ty_bare_fn(TyBareFn);
+        visit_ty_trait_object(TyTraitObject);
+        visit_ty_impl_trait(TyImplTrait);
+        visit_ty_unsafe_binder(TyUnsafeBinder);
         visit_expr(Expr);
         visit_expr_kind(ExprKind);
         visit_expr_match(Match);
@@ -85,8 +105,14 @@ pub trait Pass {
         visit_closure(Closure);
         visit_closure_arg(ClosureArg);
         visit_fn(Fn);
+        visit_self_param(SelfParam);
+        visit_fn_param_kind(FnParamKind);
         visit_fn_param(FnParam);
+        visit_safety(Safety);
+        visit_extern(Extern);
+        visit_fn_qualifiers(FnQualifiers);
         visit_fn_ret(FnRet);
+        visit_coroutine_kind(CoroutineKind);
         visit_async_block(AsyncBlock);
         visit_try_block(TryBlock);
         visit_const_block(ConstBlock);
@@ -104,13 +130,112 @@ pub trait Pass {
         visit_return(Return);
         visit_yield(Yield);
         visit_become(Become);
+        visit_yeet(Yeet);
+        visit_builtin(Builtin);
         visit_range_limits(RangeLimits);
         visit_expr_range(ExprRange);
         visit_expr_call(ExprCall);
+        visit_bin_op(BinOp);
+        visit_expr_binary(ExprBinary);
+        visit_expr_assign(ExprAssign);
+        visit_assign_op(AssignOp);
+        visit_expr_assign_op(ExprAssignOp);
+        visit_expr_cast(ExprCast);
+        visit_un_op(UnOp);
+        visit_expr_unary(ExprUnary);
+        visit_borrow_kind(BorrowKind);
+        visit_expr_ref(ExprRef);
+        visit_member(Member);
+        visit_expr_field(ExprField);
+        visit_expr_method_call(ExprMethodCall);
+        visit_index_inner(IndexInner);
+        visit_expr_index(ExprIndex);
+        visit_expr_try(ExprTry);
+        visit_expr_await(ExprAwait);
+        visit_expr_use(ExprUse);
+        visit_expr_postfix_try(ExprPostfixTry);
+        visit_expr_postfix_match(ExprPostfixMatch);
         visit_stmt(Stmt);
         visit_stmt_kind(StmtKind);
+        visit_local(Local);
+        visit_local_init(LocalInit);
         visit_ty_alias(TyAlias);
+        visit_fn_body(FnBody);
+        visit_item_init(ItemInit);
+        visit_assoc_item_kind(AssocItemKind);
+        visit_assoc_item(AssocItem);
+        visit_assoc_items(AssocItems);
+        visit_trait(Trait);
+        visit_trait_alias(TraitAlias);
+        visit_impl_of_trait(ImplOfTrait);
+        visit_impl(Impl);
+        visit_use_group(UseGroup);
+        visit_use_tree(UseTree);
+        visit_use(Use);
+        visit_extern_crate(ExternCrate);
+        visit_foreign_item_kind(ForeignItemKind);
+        visit_foreign_item(ForeignItem);
+        visit_foreign_items(ForeignItems);
+        visit_foreign_mod(ForeignMod);
+        visit_meta_var(MetaVar);
+        visit_rep_op(RepOp);
+        visit_repetition(Repetition);
+        visit_matcher_tree(MatcherTree);
+        visit_matcher(Matcher);
+        visit_macro_rule(MacroRule);
+        visit_macro_rules_body(MacroRulesBody);
+        visit_macro_rules(MacroRules);
+        visit_macro2_rules(Macro2Rules);
+        visit_macro2_body(Macro2Body);
+        visit_macro2(Macro2);
+        visit_named_field(NamedField);
+        visit_named_fields(NamedFields);
+        visit_tuple_field(TupleField);
+        visit_tuple_fields(TupleFields);
+        visit_fields(Fields);
+        visit_struct_body(StructBody);
+        visit_struct(Struct);
+        visit_union(Union);
+        visit_variant(Variant);
+        visit_variants(Variants);
+        visit_enum(Enum);
+        visit_generics(Generics);
+        visit_generic_param(GenericParam);
+        visit_generic_param_kind(GenericParamKind);
+        visit_lifetime_param(LifetimeParam);
+        visit_type_param(TypeParam);
+        visit_type_param_default(TypeParamDefault);
+        visit_const_param(ConstParam);
+        visit_const_param_default(ConstParamDefault);
+        visit_lifetime_bounds(LifetimeBounds);
+        visit_type_bounds(TypeBounds);
+        visit_type_param_bound(TypeParamBound);
+        visit_trait_bound(TraitBound);
+        visit_paren_bound(ParenBound);
+        visit_bound_constness(BoundConstness);
+        visit_bound_polarity(BoundPolarity);
+        visit_precise_capturing_arg(PreciseCapturingArg);
+        visit_precise_capturing(PreciseCapturing);
+        visit_bound_lifetimes(BoundLifetimes);
+        visit_where_clause(WhereClause);
+        visit_where_predicate(WherePredicate);
+        visit_where_lifetime_predicate(WhereLifetimePredicate);
+        visit_where_type_predicate(WhereTypePredicate);
         visit_pat(Pat);
+        visit_pat_ident(PatIdent);
+        visit_pat_lit(PatLit);
+        visit_pat_range(PatRange);
+        visit_pat_or(PatOr);
+        visit_pat_guard(PatGuard);
+        visit_pat_ref(PatRef);
+        visit_pat_box(PatBox);
+        visit_pat_tuple(PatTuple);
+        visit_pat_paren(PatParen);
+        visit_pat_slice(PatSlice);
+        visit_pat_tuple_struct(PatTupleStruct);
+        visit_pat_struct_field(PatStructField);
+        visit_pat_struct_fields(PatStructFields);
+        visit_pat_struct(PatStruct);
         // only encountered inside macros and attributes
         visit_token_stream(TokenStream);
         visit_token_tree(TokenTree);
@@ -200,9 +325,8 @@ impl<T1: Visit, T2: Visit, T3: Visit, T4: Visit> Visit for (T1, T2, T3, T4) {
 
 impl<T: Visit> Visit for Option<T> {
     fn visit<P: Pass + ?Sized>(&mut self, p: &mut P) {
-        match self {
-            Some(x) => x.visit(p),
-            None => (),
+        if let Some(x) = self {
+            x.visit(p)
         }
     }
 }
@@ -46,58 +46,13 @@ fn shrink_no_space(t: Trivia) -> Trivia {
     Trivia::trim_whitespace(shrink_single_space(t).into())
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::Print;
-    use crate::ast::Trivia;
-    use crate::passes::style::spaces::{shrink_no_space, shrink_single_space};
-
-    fn test_glue<X: Into<Trivia>>(f: fn(Trivia) -> X) -> impl Fn(&str) -> String {
-        move |s| {
-            let mut out = String::new();
-            f(crate::parse::parse_trivia(s)).into().print(&mut out);
-            out
-        }
-    }
-
-    #[test]
-    fn test_shrink_single_space() {
-        let sss = test_glue(shrink_single_space);
-        assert_eq!(" ", sss(""));
-        assert_eq!(" /* w */ ", sss("/* w */"));
-        assert_eq!(" ", sss("\n \n \n\n \n \n \n\n    "));
-        assert_eq!(
-            " /**/ /**/ ",
-            sss("
-         /**//**/
-          
-        ")
-        );
-    }
-
-    #[test]
-    fn test_shrink_no_space() {
-        let sss = test_glue(shrink_no_space);
-        assert_eq!("", sss("    \n\n \n \n \n \n"));
-        assert_eq!("/* w */", sss("/* w */"));
-        assert_eq!("", sss("\n \n \n\n \n \n \n\n    "));
-        assert_eq!(
-            "/**/ /**/",
-            sss("
-         /**//**/
-          
-        ")
-        );
-    }
-}
-
 fn fixup_path(x: &mut Path) {
     if let Some((_, trivia)) = &mut x.leading_colon {
-        *trivia = shrink_no_space(trivia.take()).into()
+        *trivia = shrink_no_space(trivia.take())
     }
     for (t1, _, t2, _) in &mut x.rest {
-        *t1 = shrink_no_space(t1.take()).into();
-        *t2 = shrink_no_space(t2.take()).into();
+        *t1 = shrink_no_space(t1.take());
+        *t2 = shrink_no_space(t2.take());
     }
 }
 
@@ -194,6 +149,18 @@ impl<T: Respace> Respace for Brackets<T> {
     }
 }
 
+impl<T: Respace> Respace for Braces<T> {
+    fn respace(&mut self, v: &mut Spaces) {
+        self.0.respace(v)
+    }
+}
+
+impl<T: Respace> Respace for Parens<T> {
+    fn respace(&mut self, v: &mut Spaces) {
+        self.0.respace(v)
+    }
+}
+
 impl<T: Respace> Respace for Box<T> {
     fn respace(&mut self, v: &mut Spaces) {
         T::respace(self, v)
@@ -201,10 +168,69 @@ impl<T: Respace> Respace for Box<T> {
 }
 
 impl Pass for Spaces {
+    fn visit_impl(&mut self, i: &mut Impl) {
+        i.respace(self);
+        i.walk(self);
+    }
     fn visit_const(&mut self, c: &mut Const) {
         c.respace(self);
     }
     fn visit_fn(&mut self, f: &mut Fn) {
         f.vis.as_mut().map(fixup_visibility_pair);
+        f.qualifiers.respace(self);
+        s1(&mut f.t1);
+        if let Some((t, generics)) = &mut f.generics {
+            s0(t);
+            generics.respace(self);
+        }
+        if let Some((t, where_)) = &mut f.where_ {
+            s1(t);
+            where_.respace(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Print;
+    use crate::ast::Trivia;
+    use crate::passes::style::spaces::{shrink_no_space, shrink_single_space};
+
+    fn test_glue<X: Into<Trivia>>(f: fn(Trivia) -> X) -> impl Fn(&str) -> String {
+        move |s| {
+            let mut out = String::new();
+            f(crate::parse::parse_trivia(s)).into().print(&mut out);
+            out
+        }
+    }
+
+    #[test]
+    fn test_shrink_single_space() {
+        let sss = test_glue(shrink_single_space);
+        assert_eq!(" ", sss(""));
+        assert_eq!(" /* w */ ", sss("/* w */"));
+        assert_eq!(" ", sss("\n \n \n\n \n \n \n\n    "));
+        assert_eq!(
+            " /**/ /**/ ",
+            sss("
+         /**//**/
+          
+        ")
+        );
+    }
+
+    #[test]
+    fn test_shrink_no_space() {
+        let sss = test_glue(shrink_no_space);
+        assert_eq!("", sss("    \n\n \n \n \n \n"));
+        assert_eq!("/* w */", sss("/* w */"));
+        assert_eq!("", sss("\n \n \n\n \n \n \n\n    "));
+        assert_eq!(
+            "/**/ /**/",
+            sss("
+         /**//**/
+          
+        ")
+        );
     }
 }
@@ -8,13 +8,14 @@ use std::panic::{UnwindSafe, catch_unwind};
 use std::path::Path;
 
 use libtest_mimic::{Failed, Trial};
+use sourcery::ParserConfig;
 use sourcery::passes::Pass;
 use walkdir::WalkDir;
 
-static IDEMPOTENCE: Variant<String> = Variant {
+static IDEMPOTENCE: Variant<(String, ParserConfig)> = Variant {
     name: "Idempotence",
-    runner: |content| {
-        let mut file = sourcery::parse(&content);
+    runner: |(content, config)| {
+        let mut file = sourcery::parse_with_config(&content, config);
         let mut content2 = String::new();
         sourcery::passes::Minify.visit_file(&mut file);
         sourcery::Print::print(&file, &mut content2);
@@ -26,10 +27,10 @@ static IDEMPOTENCE: Variant<String> = Variant {
     },
 };
 
-static MINIFY: Variant<(String, String)> = Variant {
+static MINIFY: Variant<(String, String, ParserConfig)> = Variant {
     name: "Minify",
-    runner: |(content1, minified)| {
-        let mut file = sourcery::parse(&content1);
+    runner: |(content1, minified, config)| {
+        let mut file = sourcery::parse_with_config(&content1, config);
         let mut content2 = String::new();
         sourcery::passes::Minify.visit_file(&mut file);
         sourcery::Print::print(&file, &mut content2);
@@ -65,7 +66,7 @@ impl<T: UnwindSafe + Send> Variant<T> {
                     let msg = msg
                         .downcast_ref::<String>()
                         .map(|x| &**x)
-                        .or_else(|| msg.downcast_ref::<&str>().map(|x| *x))
+                        .or_else(|| msg.downcast_ref::<&str>().copied())
                         .unwrap_or("unknown panic message");
                     let b = BACKTRACE.with(|b| b.take()).unwrap();
                     Err(Failed::from(format!(
@@ -94,15 +95,19 @@ pub fn add_tests(tests: &mut Vec<Trial>) -> color_eyre::Result<()> {
         let path = ent.into_path();
         let name = path.strip_prefix(&current_dir)?.display().to_string();
         let content = read_to_string(&path)?;
+        // files under `tests/minify/nightly` use unstable syntax
+        let config = ParserConfig {
+            nightly: path.strip_prefix(manifest_dir)?.starts_with("tests/minify/nightly"),
+        };
         if name.ends_with(".min.rs") {
-            tests.push(IDEMPOTENCE.make_trial(name, content))
+            tests.push(IDEMPOTENCE.make_trial(name, (content, config)))
         } else {
             let p = path.with_extension("min.rs");
             if !p.exists() {
                 panic!("need {}", p.display());
             }
             let expected = read_to_string(&p)?;
-            tests.push(MINIFY.make_trial(name, (content, expected)))
+            tests.push(MINIFY.make_trial(name, (content, expected, config)))
         }
     }
 
@@ -8,13 +8,14 @@ use std::panic::catch_unwind;
 use std::path::Path;
 
 use libtest_mimic::{Failed, Trial};
+use sourcery::ParserConfig;
 use walkdir::WalkDir;
 
 static VARIANTS: &[Variant] = &[
     Variant {
         name: "FilePrint",
-        runner: |content| {
-            let file = sourcery::parse(&content);
+        runner: |content, config| {
+            let file = sourcery::parse_with_config(&content, config);
             let mut content2 = String::new();
             sourcery::Print::print(&file, &mut content2);
             if content != content2 {
@@ -24,7 +25,7 @@ static VARIANTS: &[Variant] = &[
     },
     Variant {
         name: "TokenStreamPrint",
-        runner: |content| {
+        runner: |content, _| {
             let file = sourcery::parse_to_tokenstream(&content);
             let mut content2 = String::new();
             sourcery::Print::print(&file, &mut content2);
@@ -37,11 +38,11 @@ static VARIANTS: &[Variant] = &[
 
 pub struct Variant {
     name: &'static str,
-    runner: fn(String),
+    runner: fn(String, ParserConfig),
 }
 
 impl Variant {
-    pub fn make_trial(&'static self, path: String, content: String) -> Trial {
+    pub fn make_trial(&'static self, path: String, content: String, config: ParserConfig) -> Trial {
         let runner = move || {
             thread_local! {
                 static BACKTRACE: Cell<Option<Backtrace>> = const { Cell::new(None) };
@@ -52,13 +53,13 @@ impl Variant {
                 BACKTRACE.with(move |b| b.set(Some(trace)));
             }));
 
-            match catch_unwind(move || (self.runner)(content)) {
+            match catch_unwind(move || (self.runner)(content, config)) {
                 Ok(()) => Ok(()),
                 Err(msg) => {
                     let msg = msg
                         .downcast_ref::<String>()
                         .map(|x| &**x)
-                        .or_else(|| msg.downcast_ref::<&str>().map(|x| *x))
+                        .or_else(|| msg.downcast_ref::<&str>().copied())
                         .unwrap_or("unknown panic message");
                     let b = BACKTRACE.with(|b| b.take()).unwrap();
                     Err(Failed::from(format!(
@@ -85,10 +86,14 @@ pub fn add_tests(tests: &mut Vec<Trial>) -> color_eyre::Result<()> {
         let path = ent.into_path();
         let name = path.strip_prefix(&current_dir)?.display().to_string();
         let content = read_to_string(&path)?;
+        // files under `tests/pp/nightly` use unstable syntax
+        let config = ParserConfig {
+            nightly: path.strip_prefix(manifest_dir)?.starts_with("tests/pp/nightly"),
+        };
         tests.extend(
             VARIANTS
                 .iter()
-                .map(|v| v.make_trial(name.clone(), content.clone())),
+                .map(|v| v.make_trial(name.clone(), content.clone(), config)),
         );
     }
 
@@ -0,0 +1,30 @@
+struct Unit;
+struct UnitWhere<T> where T: Copy;
+pub struct Tuple(pub u8, pub(crate) u16, pub [u8; 2], u32,);
+struct TupleWhere<T>(T) where T: Copy;
+struct Empty {}
+struct EmptyTuple();
+#[derive(Debug)]
+pub(crate) struct Named<'a, T: 'a> where T: Clone {
+    #[doc = "a"]
+    pub a: T,
+    pub(super) b: [u8; 4],
+    c: u8 /* trailing */,
+}
+union U { a: u32, pub b: f32 }
+pub union Generic<T: Copy> where T: Sized { a: T }
+enum Empty2 {}
+enum E {
+    A,
+    #[cfg(x)] B(u8, pub u16),
+    C { a: u8, b: u16 },
+    D = 1 << 2,
+    E(u8) = 3,
+}
+pub enum Option<T> where T: Sized { None, Some(T), }
+fn f() {
+    struct Local;
+    enum Local2 { X }
+    union Local3 { a: u8 }
+    let union = 1;
+}
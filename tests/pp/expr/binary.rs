@@ -0,0 +1,33 @@
+fn binary() {
+    a + b * c - d / e % f;
+    x == y && z || w;
+    a<<b >> c & d ^ e | f;
+    a< b;
+    a  /* lhs */ +   /* rhs */ b;
+    1 < 2;
+    a >= b && c <= d && e != f;
+    foo(a + b, c);
+}
+
+fn assign() {
+    a = b = c;
+    a += 1;
+    a -= 1; a *= 2; a /= 2; a %= 2;
+    a ^= b; a &= b; a |= b;
+    a <<= 1;
+    a >>= 1;
+    a =   b + c;
+}
+
+fn range() {
+    a..b;
+    a..=b;
+    a..;
+    ..b;
+    ..;
+    x = a + 1..b * 2;
+    for i in 0..n {}
+    for i in 0.. {}
+}
+
+const A: bool = 1 + 2 * 3 == 7 && 8 >> 1 > 3;
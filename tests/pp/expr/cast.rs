@@ -0,0 +1,13 @@
+fn cast() {
+    x as u8;
+    x as u8 as char;
+    x  /* a */ as  /* b */ u8;
+    -x as i32;
+    a * b as c + d;
+    a as u8 > b;
+    a as u8 == b as u8;
+    (x as u8) < y;
+    a..b as usize;
+    x = y as u8;
+    f(x as ::std::primitive::u8);
+}
@@ -0,0 +1,13 @@
+fn main() {
+    let a = || 1;
+    let b = | | 2;
+    let c = move |x| x + 1;
+    let d = async move |x: u8, y| -> u8 { x };
+    let e = async || {};
+    let j = |&(a, b), Point { x, .. }| a;
+    let k = |_| -> () { () };
+    let l = async {};
+    let m = async move { 1 };
+    async move {}.await;
+    f(|| {}, move || (), async || ());
+}
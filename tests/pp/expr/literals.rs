@@ -0,0 +1,7 @@
+fn literals() {
+    let ints = (1, 12_u8, 0o100, 0xffi64, 0b1010, 1f32);
+    let floats = (1.0, 12.34f32, 1e3, 2.5E-10_f64);
+    let chars = ('a', '\'', '\u{1F600}', b'a', b'\\');
+    let strs = ("abc", "a\"b", b"abc", c"abc", "abc"suffix);
+    let raw = (r"abc", r#"a"b"#, r##"a"#b"##, br#"abc"#, cr"abc", cr#"a"b"#);
+}
@@ -0,0 +1,34 @@
+fn postfix() {
+    a.b;
+    a.0;
+    a.0.b.1.1.c;
+    a .  /* dot */ b . 0;
+    a.b();
+    a.b(c, d,);
+    a . b ( c );
+    a.parse::<u8>();
+    a.f :: < 'a, T > ();
+    a[0];
+    a[b][c + 1];
+    a [ /* i */ b ];
+    a?;
+    a??.b?;
+    a.await;
+    a.b().await?.c[0].1;
+    f(a)(b);
+    -a.b;
+    &a.b()?;
+    a.0.1 + a.2;
+    a.1. b;
+    a.0.1. /* c */ 2.c();
+    x.y = z;
+}
+
+fn blocks() {
+    {}.f();
+    match x {}?;
+    if a { b } else { c }.d.e;
+    unsafe { f() }.g + 1;
+    loop {}
+    -a;
+}
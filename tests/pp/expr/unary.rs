@@ -0,0 +1,28 @@
+fn unary() {
+    -a;
+    !b;
+    *c;
+    - /* neg */ 1;
+    !!x;
+    **y;
+    -a * -b;
+    a<-b;
+    !a && !b;
+}
+
+fn borrow() {
+    &a;
+    &mut a;
+    & mut  /* m */ a;
+    &&a;
+    && mut a;
+    &&&a;
+    &raw const a;
+    &raw mut a;
+    & raw  const a;
+    &raw;
+    &*a;
+    &mut **a;
+    a & &b;
+    a && &&b;
+}
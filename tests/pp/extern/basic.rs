@@ -0,0 +1,22 @@
+extern crate alloc;
+pub extern crate std as /* renamed */ sstd;
+extern crate self as this_crate;
+
+extern "C" {
+    fn abort();
+    static mut errno: i32;
+}
+
+#[link(name = "m")]
+unsafe extern "C" {
+    #![allow(dead_code)]
+
+    pub safe fn sqrt(x: f64) -> f64;
+    pub unsafe fn strlen(p: Ptr) -> usize;
+    unsafe static environ: Env;
+    safe static  TICKS : u64;
+}
+
+extern {}
+
+static mut COUNTER: u32 = 0;
@@ -0,0 +1,3 @@
+#!/usr/bin/env run-cargo-script
+// the next line is not an inner attribute
+fn main() {}
@@ -0,0 +1,24 @@
+pub const unsafe extern "C" fn a() {}
+async fn b() {}
+pub(crate) async unsafe fn c() {}
+const fn d() {}
+extern fn e() {}
+safe fn s();
+trait T {
+    fn a(self);
+    fn b(mut self);
+    fn c(&self);
+    fn d(&mut self, x: u8);
+    fn e(&'a self);
+    fn f(&'a mut self);
+    fn g(self: Rc);
+    fn h(mut self: Self, #[attr] x: u8);
+    fn i(#[attr] &self);
+    unsafe fn j(&  'a  mut  self );
+}
+fn f() {
+    const fn g() {}
+    async fn h() {}
+    unsafe fn i() {}
+    unsafe {}
+}
@@ -0,0 +1,11 @@
+fn a<T>() {}
+fn b<'a, 'b: 'a + 'static, T: Clone + Send + 'a, const N: usize, const M: i32 = -1, U = T,>(x: T) -> U where T: Copy, 'a: 'b, for<'c> U: Sized + 'c, {}
+fn c < #[may_dangle] T : Copy , const N : usize = { 3 } > ( ) where T : ::core::marker::Copy + , {}
+fn d<>() where {}
+fn e<T:>() {}
+type A<T> = T;
+type B<T> where T: Copy = T;
+type C<T: Clone,> = T where T: Copy;
+fn f() {
+    fn g<T>() -> T where T: Default { loop {} }
+}
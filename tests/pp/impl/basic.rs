@@ -0,0 +1,16 @@
+impl Foo {}
+impl<T> Foo for Bar where T: Copy {
+    #![allow(unused)]
+
+    type X = u8;
+    const N: usize = 1;
+    fn f(x: u8) {}
+}
+unsafe impl Sync for X {}
+impl [u8; 3] {
+    pub fn len() -> usize { 3 }
+}
+impl <T as Trait>::Assoc {}
+fn f() {
+    impl Local for X {}
+}
@@ -0,0 +1,22 @@
+lazy_static! {
+    static ref X: u32 = 1;
+}
+
+thread_local!(static Y: u32 = 2) ;
+#[cfg(test)]
+::std::thread_local![static Z: u32 = 3];
+my_macro!{}
+my_macro! /* c */ {};
+
+impl Foo {
+    delegate!(fn a());
+    items! { fn b() {} }
+}
+
+trait Bar {
+    assoc!{}
+}
+
+extern "C" {
+    foreign!(fn c(););
+}
@@ -0,0 +1,25 @@
+macro_rules! empty {}
+
+macro_rules! square {
+    ($x:expr) => { $x * $x };
+}
+
+#[macro_export]
+macro_rules! vec_of {
+    () => { Vec::new() };
+    ( $( $x : expr ),+ $(,)? ) => {{
+        let mut v = Vec::new();
+        $( v.push($x); )+
+        v
+    }};
+    [$($k:ident => $v:tt)*] => ( $( ($k, $v) )* );
+    ($($t:tt)+; $a:lifetime) => {}
+}
+
+macro_rules! parens ( ($($i:ident)|*) => { 0 } );
+macro_rules! brackets [ ($x:ty) => { 1 }; ] ;
+
+fn f() {
+    macro_rules! local { ($e:expr) => { $e } }
+    local!(1);
+}
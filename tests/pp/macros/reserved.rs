@@ -0,0 +1,4 @@
+m!(#"guarded"# ##"two \" hashes"## #"sfx"#suffix ###"extra"####);
+m!(## # ### foo);
+m!(foo#bar baz"str" q'c' prefix#"guarded"#);
+m!('life#x 'a #'b);
@@ -0,0 +1,7 @@
+fn main() {
+    let f = static || yield 1;
+    static move || {};
+    let g = static /* c */ async move || ();
+    let h = for<'a> |x: &'a u8| -> &'a u8 { x };
+    let i = for<'a> move || {};
+}
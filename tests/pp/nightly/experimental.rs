@@ -0,0 +1,24 @@
+fn main() {
+    do yeet;
+    do  /* c */ yeet Err(e);
+    let off = builtin # offset_of(Struct, field.0);
+    let args = builtin #format_args("{}", 1);
+    let x = y.use;
+    let v = f(). use .len();
+    let r = x.try;
+    let s = f(). try .len();
+    x.match {
+        0 => {}
+        _ => {}
+    }
+    let n = x . match { Some(v) => v, None => 0 }.abs();
+    m!(k#match k#yield);
+    let t = try { f()? };
+    become g(1);
+    let k = f::<const { N + 1 }, { M }>();
+    match n {
+        const { N + 1 } => {}
+        const { 1 }..=const { 9 } => {}
+        _ => {}
+    }
+}
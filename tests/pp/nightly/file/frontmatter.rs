@@ -0,0 +1,8 @@
+
+---
+[dependencies]
+regex = "1"
+---
+//! A script without a shebang.
+
+use regex::Regex;
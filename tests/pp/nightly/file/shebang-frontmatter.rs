@@ -0,0 +1,10 @@
+#!/usr/bin/env -S cargo +nightly -Zscript
+---cargo
+[package]
+edition = "2024"
+
+[dependencies]
+clap = { version = "4.2", features = ["derive"] }
+---
+
+fn main() {}
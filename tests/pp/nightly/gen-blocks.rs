@@ -0,0 +1,7 @@
+fn main() {
+    let n = gen { yield 1 };
+    let o = gen move {};
+    let p = async gen {};
+    let q = async  gen /* c */ move { yield 1 };
+    gen {};
+}
@@ -0,0 +1,29 @@
+impl<T> Foo for Bar where T: Copy {
+    default fn g() {}
+    #[inline]
+    default  const M: u8 = 2;
+    default type Y = u16;
+}
+impl !Send for X {}
+unsafe impl<'a> !Sync for X {}
+default unsafe impl Foo for X {}
+const impl Foo for X {}
+impl<T> const Foo for X {}
+auto trait Auto {}
+pub unsafe   auto trait Both<T> where T: Copy { }
+trait Alias<T> = Foo + Bar where T: Copy;
+trait Alias2 = Foo;
+extern "C" fn variadic(x: u8, ...) {}
+extern "C" fn variadic2(x: u8, args: ...,) {}
+unsafe extern "C" {
+    type Opaque;
+    pub type Handle;
+}
+pub macro two($a:expr, $b:expr) {
+    $a + $b
+}
+
+pub(crate) macro arms {
+    ($x:ident) => { $x },
+    ($($x:ident),* ; [$y:literal]) => { ($($x,)* $y) },
+}
@@ -0,0 +1,9 @@
+fn nightly() {
+    match never {
+        const { N + 1 } => {}
+        Some(!) => {}
+        (x if x > 0, y) => {}
+        S { a: a if a, .. } => {}
+    }
+    let box boxed = b;
+}
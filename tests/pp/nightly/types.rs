@@ -0,0 +1,10 @@
+fn g(x: impl ?Sized + (Send) + ~const Clone + const Copy + async Future) {}
+trait T: ?Sized + for<'a> Tr + (for<'b> X) + !Unpin {}
+fn h<T: ~ const Default + ? Sized>() where T: 'static + (?Sized) {}
+type F = &pin mut T;
+type G = &'a pin const T;
+type I = unsafe<'a> &'a u8;
+type J = unsafe<'a, 'b> fn(&'a u8, &'b u8);
+fn f(x: &str) -> (u8, !) {}
+type Never = !;
+fn n(_: !) -> Result<u8, !> { loop {} }
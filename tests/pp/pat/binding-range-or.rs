@@ -0,0 +1,24 @@
+fn f(ref x: u8, mut y: u8, ref  mut /* c */ z: u8, whole @ Point { .. }: Point, -1: i8) {}
+fn main() {
+    match c {
+        | 1..=9 | b'a' => {}
+        -5 ..= -1 => {}
+        'a'..'z' | 'A'.. => {}
+        ..=0 | ..-10 => {}
+        i32::MIN..=MAX | <T>::LO..<T>::HI => {}
+        ref mut x @ Some(_) => {}
+        n @ (1 | 2) => {}
+        s @ "str" | s @ 1.5 => {}
+        [first, rest @ .., last] => {}
+        [1.., ..] => {}
+        Some(Foo { x: 0..=5, y: ref y }) if y > 0 => {}
+        pat!(inner) => {}
+        m::pat! { inner } => {}
+        &mut (a | b) | &(c,) => {}
+        _ => {}
+    }
+    for (A | B) | C in it {}
+    if let | Some(x) | Ok(x) = y {}
+    let Some(x) | Err(x) = z else { return };
+    let f = |&(a, b): &(u8, u8), mut c| a;
+}
@@ -0,0 +1,19 @@
+fn f(Point { x, y: ref_y, .. }: Point, (a, b): (u8, u8), [first, .., last]: [u8; 4]) {}
+fn g(&x: &u8, & mut y: &mut u8, &&z: &&u8, _: u8, (( p )): u8, (): (), (t,): (u8,)) {}
+fn h(Wrapper(inner): Wrapper, Tuple(a, .., z,): Tuple, S { 0: a, 1 : b }: S, E {}: E) {}
+fn main() {
+    for (k, v) in map {}
+    let Some(x) = opt else { return };
+    let (a, (b, c), ..) = t;
+    let [a, b, ..] = s;
+    let Foo { #[cfg(a)] a, /* c */ b: Bar(..) , } = f;
+    let (..) = u;
+    match p {
+        None => {}
+        std::option::Option::Some(_) => {}
+        Self::A => {}
+        <T as Trait>::B => {}
+        <T>::C { .. } => {}
+        ::core::cmp::Ordering::Less => {}
+    }
+}
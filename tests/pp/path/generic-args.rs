@@ -0,0 +1,21 @@
+type A = Vec<u8>;
+type B = Vec<Vec<Option<u8>>>;
+type C = HashMap<String, Vec<u8>, >;
+type D = [T; { N + 1 }];
+type E = Foo<3, -1, { N + 1 }, 'a>;
+type F = dyn Iterator<Item = u8> + Send;
+type G = Box<dyn Fn(u8, &str) -> bool + 'static>;
+type H = Box<dyn FnMut()>;
+type I = Foo<Assoc<'a> = u8, N = 3>;
+type J = Vec::<u8>;
+type K = <Vec<u8> as IntoIterator>::Item;
+fn f<T: Tr<Assoc: Send>, U>() where T::method(..): Send, U: Trait<Gat<'a>: Copy> {}
+fn g() {
+    let v = (0..3).collect::<Vec<_>>();
+    let w: Vec<u8>= Vec::<u8>::new();
+    let x = Vec::<Vec<u8>>::new();
+    let y: Vec<Vec<u8>>= vec![];
+    let z = a < b && c > d;
+    let s = 1 << 2 >> 3;
+}
+impl<T: Default> From<Vec<T>> for Wrapper<T> {}
@@ -0,0 +1,12 @@
+fn f() {
+    #![allow(unused)]
+    #![cfg_attr(foo, bar)]
+
+    let a = 1;
+}
+
+fn g() {
+    #![allow(unused)]
+}
+
+fn h() { #![inline] }
@@ -0,0 +1,10 @@
+fn outer() {
+    fn inner(a: u8) -> u8 { a }
+    #[inline]
+    const C: u8 = 1;
+    pub(crate) static S: u8 = 2;
+    type T = u8;
+    mod m {}
+    let x = unsafe { inner(C) };
+    const { 1 };
+}
@@ -0,0 +1,17 @@
+fn locals() {
+    let a;
+    let b = 1;
+    let c: u8 = 2;
+    let d: [u8; 2];
+    let  /* pat */ e  :  /* ty */ u8  =  /* init */ 3  /* semi */ ;
+    #[allow(unused)]
+    let f = a + b * c;
+    let g = if a { b } else { c };
+    let h = S { x: 1 };
+    let x = y else { return };
+    let x = f()? else {
+        panic!()
+    };
+    let z = a  else  /* else */  { loop {} };
+    let q = match x {};
+}
@@ -0,0 +1,11 @@
+fn f() {
+    println!("{}", 1);
+    vec![1, 2] ;
+    thread_local! { static X: u8 = 0; }
+    foo! { a b c };
+    #[rustfmt::skip]
+    foo!().bar();
+    ::std::print!("x").len();
+    bar! {}.baz;
+    assert!(true)
+}
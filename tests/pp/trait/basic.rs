@@ -0,0 +1,21 @@
+trait Empty {}
+pub trait Foo: Bar + 'static {
+    #![allow(unused)]
+
+    type X;
+    type Y: Clone + Send where Self: Sized;
+    type Z = u8;
+    const N: usize;
+    const M: usize = 3;
+    fn f(x: u8);
+    fn g<T>(x: T) -> u8 where T: Copy { 0 }
+    #[doc = "h"]
+    pub fn h() {}
+}
+unsafe trait Send2 {}
+type Bounded: Copy = u8;
+fn f() {
+    trait Local {}
+    let auto = 1;
+    unsafe { }
+}
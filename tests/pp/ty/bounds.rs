@@ -0,0 +1,11 @@
+type A = fn();
+type B = unsafe extern "C" fn(u8, ...) -> u8;
+type C = for<'a> fn(x: &'a u8, _: u8) -> &'a u8;
+type D = extern fn(#[attr] a: u8, #[attr] ...);
+type E = &'a (dyn Any + Send + 'a);
+type F = &dyn for<'a> Trait;
+fn f() -> impl Iterator + use<'a, T> {}
+fn g(x: impl ?Sized + (Send)) {}
+trait T: ?Sized + for<'a> Tr + (for<'b> X) {}
+fn h<T: ? Sized>() where T: 'static + (?Sized) {}
+fn i() -> impl Sized + use<> {}
@@ -0,0 +1,8 @@
+type A = <T as Trait>::Assoc;
+type B = < T >  ::  Assoc;
+type C = <<T as A>::B as C>::D;
+type D = ty![u8];
+type E = my::ty! { a b c };
+type H = &pin;
+type K = unsafe fn();
+fn f(x: <Self as Iterator>::Item) -> Vec![u8] {}
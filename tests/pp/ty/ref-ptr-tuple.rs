@@ -0,0 +1,9 @@
+fn f(x: &str, y: & 'a mut  T, z: &&u8) -> (u8, ()) {}
+type Ptr = *const *mut /* c */ u8;
+type Unit = ( );
+type Single = (u8 ,);
+type Paren = (u8);
+type Triple = (A, (B, C), [D; 2],);
+static S: &'static [&(dyn_free, _)] = &[];
+fn g() -> ! { loop {} }
+fn h(_: _) where (A, B): Copy, &'a T: Clone {}
@@ -0,0 +1,17 @@
+use a;
+use a::b;
+pub use ::std::collections::HashMap;
+pub(crate) use a::b as c;
+use a::b as _;
+use a::*;
+use a::{self, b, c::d as e, f::*, g::{h, i},};
+use {a, b};
+use ::{a};
+use a::{};
+use  a  ::  {  b  ,  c  }  ;
+fn f() {
+    use std::io::Write;
+}
+#[cfg(x)]
+use crate::x;
+use super::super::x;
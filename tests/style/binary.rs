@@ -0,0 +1,3 @@
+const A: i32 = 1+2   *3;
+const B: bool = a==b&&c  <  d;
+const C: R = a ..  b  +1;
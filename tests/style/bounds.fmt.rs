@@ -0,0 +1,4 @@
+fn f<T: ?Sized + for<'a> Tr>() where T: (?Sized) + use<'a, T> {}
+const X: &dyn A + B = x;
+const Y: unsafe extern "C" fn(a: u8, ...) -> u8 = x;
+const Z: for<'a> fn(&'a u8) = x;
@@ -0,0 +1,5 @@
+const A: u8 = f ( a,b );
+const B: u8 = x . foo();
+const C: u8 = x.y ::<u8>( 1 , 2 ).z( );
+const D: (u8, u8) = ( a ,b );
+const E: [u8; 2] = [ a,b ];
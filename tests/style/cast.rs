@@ -0,0 +1,2 @@
+const A: char = x   as   u8 as  /* c */ char;
+const B: u8 = -x/*a*/as u8+1;
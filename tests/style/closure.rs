@@ -0,0 +1,7 @@
+const A: fn() = move  | |{};
+const B: fn(u8) -> u8 = | x |x+1;
+const C: fn(u8, u8) -> u8 = |a:u8 ,b |  -> u8 { a };
+const D: fn() = async   move||{};
+const E: fn(u8) = | #[a]x |();
+const F: () = async  move {};
+const G: () = async{};
@@ -0,0 +1,2 @@
+pub   async    fn a() {}
+pub(crate)  const  unsafe   extern  "C"   fn   b() {}
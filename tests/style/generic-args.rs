@@ -0,0 +1,6 @@
+const A: Vec < u8 > = x;
+const B: HashMap<  K ,V  > = x;
+const C: Box<dyn Fn ( u8 ,  u16 )  ->   bool> = x;
+const D: Foo<Item  =  u8, N=3, Assoc :  Send> = x;
+const E: Vec :: < u8 > = x;
+fn f<T>() where T::method ( .. ): Send {}
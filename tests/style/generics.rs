@@ -0,0 +1,3 @@
+fn a <T>() {}
+fn b< 'a ,'b : 'a+'static , T:Clone+Send ,const  N :usize=3,U = T ,>(x: T) -> U   where T:Copy,'a :'b , for < 'c >  U : Sized+'c {}
+fn c<#[may_dangle]  T>() where  T :  ::core::marker::Copy {}
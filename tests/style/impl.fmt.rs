@@ -0,0 +1,10 @@
+impl<T> Foo for Bar {}
+unsafe impl<'a> Send for X where T: Copy {}
+impl Foo for X {
+    fn f<T>() {}
+}
+impl S {
+    const A: u8 = x.f(a, b);
+    const B: fn(u8) -> u8 = move |x| x + 1;
+    const C: u8 = a.0 + f();
+}
@@ -0,0 +1 @@
+fn f<T: ~const Default + ?Sized + const Copy + async Fn + !Unpin>() {}
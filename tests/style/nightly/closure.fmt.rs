@@ -0,0 +1,3 @@
+const A: fn() = for<'a> || {};
+const B: fn() = static move || {};
+const C: fn() = for<'a, 'b> static async move |x: &'a u8| {};
@@ -0,0 +1,3 @@
+const A: () = gen move {};
+const B: () = async gen {};
+const C: () = async gen move {};
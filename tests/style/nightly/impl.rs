@@ -0,0 +1,6 @@
+unsafe   impl <'a>  !Send for  X   where T :Copy  {}
+impl  const Foo for X{
+    fn f< T >() {}
+}
+const  impl Foo for X {}
+default   impl<T> Foo for T {}
@@ -0,0 +1,5 @@
+const A: u8 = x . match   { _  =>1 };
+const B: u8 = x.y /* c */.match{
+    Some(z)if z>0=>  z ,
+    _ =>0
+};
@@ -0,0 +1,4 @@
+const C: &pin mut T = x;
+const D: &'a pin const T = x;
+const E: unsafe<'a> &'a u8 = x;
+const F: (u8, !,) = x;
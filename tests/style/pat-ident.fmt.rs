@@ -0,0 +1,9 @@
+const A: u8 = match x {
+    ref mut y @ Some(_) => 1,
+    1..=9 => 2,
+    A.. => 3,
+    ..=Z => 4,
+    -1 => 5,
+    | a | b | c => 6,
+    _ => 0
+};
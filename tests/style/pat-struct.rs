@@ -0,0 +1,11 @@
+const A: u8 = match x {
+    & y=>1,
+    &mut  Some (z)=>2,
+    Point{x,y :0 , .. }=>3,
+    Point {  }=>4,
+    Point{ .. }=>5,
+    ( a,b )=>6,
+    [a , .. ,b]=>7,
+    Self :: A=>8,
+    _ =>0
+};
@@ -0,0 +1,2 @@
+const A: <T as Trait>::Assoc = x;
+const B: <<T as A>::B as C>::D = x;
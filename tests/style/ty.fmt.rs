@@ -0,0 +1,6 @@
+const A: &'a mut T = x;
+const B: &mut T = x;
+const C: *const u8 = x;
+const D: (u8, i8,) = x;
+const E: (u8) = x;
+const F: &_ = x;
@@ -0,0 +1,4 @@
+const A: i32 = -  1 + !   a;
+const B: T = &  mut    a;
+const C: T = &   raw   const  a;
+const D: T = &&   a;